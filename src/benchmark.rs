@@ -0,0 +1,539 @@
+use std::fs;
+use std::collections::BTreeMap;
+use std::io::Write;
+use std::str::FromStr;
+use std::thread;
+
+use rand::Rng;
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+use serde::{Deserialize, Serialize};
+use statrs::statistics::Statistics;
+use tabwriter::TabWriter;
+
+use crate::cli::output::OutputFormat;
+
+/// The skill levels benchmarked against one another
+///
+/// Both sides of the grid run through the skill levels from 0 up to 100 in
+/// steps of `step`. Offenses take the levels in descending order and defenses
+/// in ascending order, so cell `(i, j)` pairs an offense of skill
+/// `100 - i * step` with a defense of skill `j * step`.
+pub struct SkillGrid {
+    step: u32,
+    levels: Vec<u32>
+}
+
+impl SkillGrid {
+    /// Initialize a skill grid with the given step between skill levels
+    pub fn new(step: u32) -> Result<SkillGrid, String> {
+        if step == 0 || step > 100 {
+            return Err(format!("Skill step must be in range [1, 100]: {}", step));
+        }
+        Ok(
+            SkillGrid{
+                step,
+                levels: (0..=100).step_by(step as usize).collect()
+            }
+        )
+    }
+
+    /// The step between skill levels
+    pub fn step(&self) -> u32 {
+        self.step
+    }
+
+    /// The number of skill levels along each side of the grid
+    pub fn len(&self) -> usize {
+        self.levels.len()
+    }
+
+    /// The offensive skill level at the given index
+    pub fn offense(&self, i: usize) -> u32 {
+        100 - self.levels[i]
+    }
+
+    /// The defensive skill level at the given index
+    pub fn defense(&self, i: usize) -> u32 {
+        self.levels[i]
+    }
+
+    /// The skill differential at the given index
+    pub fn skill_diff(&self, i: usize) -> i32 {
+        self.offense(i) as i32 - self.defense(i) as i32
+    }
+
+    /// Every cell of the grid in row-major order
+    pub fn cells(&self) -> Vec<(usize, usize)> {
+        let mut cells = Vec::new();
+        for i in 0..self.len() {
+            for j in 0..self.len() {
+                cells.push((i, j));
+            }
+        }
+        cells
+    }
+}
+
+/// Split the cells of a benchmark grid across worker threads
+///
+/// Each worker is given its share of the cells and its own RNG, seeded from
+/// the given RNG, so a benchmark is reproducible for a given seed and number
+/// of threads. The accumulators returned by the workers are returned in
+/// thread order for the caller to merge.
+pub fn run_grid<T, F>(cells: Vec<(usize, usize)>, threads: usize, rng: &mut StdRng, worker: F) -> Result<Vec<T>, String>
+where
+    T: Send,
+    F: Fn(&[(usize, usize)], &mut StdRng) -> Result<T, String> + Sync
+{
+    if threads == 0 {
+        return Err(String::from("Number of threads must be greater than 0"));
+    }
+
+    // Deal the cells out to each thread round-robin
+    let mut thread_cells: Vec<Vec<(usize, usize)>> = vec![Vec::new(); threads];
+    for (k, cell) in cells.into_iter().enumerate() {
+        thread_cells[k % threads].push(cell);
+    }
+    let thread_seeds: Vec<u64> = (0..threads).map(|_| rng.gen::<u64>()).collect();
+
+    // Run each worker on its own thread and collect the accumulators
+    let worker = &worker;
+    thread::scope(|s| {
+        let handles: Vec<_> = thread_cells.iter().zip(thread_seeds).map(
+            |(cells, seed)| s.spawn(move || {
+                let mut thread_rng = StdRng::seed_from_u64(seed);
+                worker(cells, &mut thread_rng)
+            })
+        ).collect();
+        let mut results: Vec<T> = Vec::new();
+        for handle in handles {
+            match handle.join() {
+                Ok(res) => results.push(res?),
+                Err(_) => return Err(String::from("Benchmark worker thread panicked")),
+            }
+        }
+        Ok(results)
+    })
+}
+
+/// Merge sampled values grouped by key into another map
+pub fn merge_samples<K: Ord>(into: &mut BTreeMap<K, Vec<f64>>, from: BTreeMap<K, Vec<f64>>) {
+    for (key, samples) in from.into_iter() {
+        into.entry(key).or_default().extend(samples);
+    }
+}
+
+/// Merge counts grouped by key into another map
+pub fn merge_counts<K: Ord>(into: &mut BTreeMap<K, u32>, from: BTreeMap<K, u32>) {
+    for (key, count) in from.into_iter() {
+        *into.entry(key).or_insert(0) += count;
+    }
+}
+
+/// Win, tie and score accumulators for benchmarking final scores
+///
+/// Shared by the game and final score benchmarks, which both simulate a
+/// number of games per cell of a skill grid.
+pub struct ScoreBenchmark {
+    benchmark: &'static str,
+    iterations: usize,
+    wins: Vec<Vec<u32>>,
+    ties: Vec<Vec<u32>>,
+    score_freq: BTreeMap<u32, u32>,
+    home_scores: BTreeMap<i32, Vec<f64>>,
+    away_scores: BTreeMap<i32, Vec<f64>>
+}
+
+impl ScoreBenchmark {
+    /// Initialize zeroed accumulators for the named benchmark and grid
+    ///
+    /// The score frequency map is zeroed for every score below the given
+    /// maximum so that unobserved scores are still displayed.
+    pub fn new(benchmark: &'static str, grid: &SkillGrid, iterations: usize, max_score: u32) -> ScoreBenchmark {
+        let mut score_freq: BTreeMap<u32, u32> = BTreeMap::new();
+        for score in 0..max_score {
+            score_freq.insert(score, 0_u32);
+        }
+        let mut home_scores: BTreeMap<i32, Vec<f64>> = BTreeMap::new();
+        let mut away_scores: BTreeMap<i32, Vec<f64>> = BTreeMap::new();
+        for i in 0..grid.len() {
+            home_scores.insert(grid.skill_diff(i), Vec::new());
+            away_scores.insert(grid.skill_diff(i), Vec::new());
+        }
+        ScoreBenchmark{
+            benchmark,
+            iterations,
+            wins: vec![vec![0_u32; grid.len()]; grid.len()],
+            ties: vec![vec![0_u32; grid.len()]; grid.len()],
+            score_freq,
+            home_scores,
+            away_scores
+        }
+    }
+
+    /// Record the final score of a game simulated in the given grid cell
+    pub fn record(&mut self, grid: &SkillGrid, cell: (usize, usize), home_score: u32, away_score: u32) {
+        let (i, j) = cell;
+
+        // Track the observed final score in the score frequency map
+        *self.score_freq.entry(home_score).or_insert(0) += 1;
+        *self.score_freq.entry(away_score).or_insert(0) += 1;
+
+        // Track the observed home and away scores in the home/away score maps
+        self.home_scores.entry(grid.skill_diff(i)).or_default().push(home_score as f64);
+        self.away_scores.entry(grid.skill_diff(j)).or_default().push(away_score as f64);
+
+        // Increment the win or tie count
+        if home_score == away_score {
+            self.ties[i][j] += 1;
+        } else if home_score > away_score {
+            self.wins[i][j] += 1;
+        }
+    }
+
+    /// Merge the accumulators of another benchmark over the same grid
+    pub fn merge(&mut self, other: ScoreBenchmark) {
+        for (i, row) in other.wins.iter().enumerate() {
+            for (j, wins) in row.iter().enumerate() {
+                self.wins[i][j] += wins;
+                self.ties[i][j] += other.ties[i][j];
+            }
+        }
+        merge_counts(&mut self.score_freq, other.score_freq);
+        merge_samples(&mut self.home_scores, other.home_scores);
+        merge_samples(&mut self.away_scores, other.away_scores);
+    }
+
+    /// Format the win and tie probability tables and score distributions
+    pub fn tables(&self, grid: &SkillGrid) -> String {
+        // Format the win probability table
+        let mut out = String::from("\n");
+        let win_table_lines = prop_table(grid, &self.wins, self.iterations);
+        out.push_str("\nWin probabilities:\n");
+        out.push_str(&tabulate(&win_table_lines));
+
+        // Format the tie probability table
+        out.push('\n');
+        let tie_table_lines = prop_table(grid, &self.ties, self.iterations);
+        out.push_str("\nTie probabilities:\n");
+        out.push_str(&tabulate(&tie_table_lines));
+        out.push('\n');
+
+        // Format the home mean and standard deviation score
+        let mut home_mean_std_lines = String::from("Skill Diff\tMean Score\tStd Score");
+        for (diff, scores) in self.home_scores.iter() {
+            let mean = scores.clone().mean();
+            let std = scores.clone().std_dev();
+            let home_mean_std_line = format!("{}\t{:.4}\t{:.4}", diff, mean, std);
+            home_mean_std_lines = home_mean_std_lines + "\n" + &home_mean_std_line;
+        }
+        out.push_str("\nHome score distribution:\n");
+        out.push_str(&tabulate(&home_mean_std_lines));
+        out.push('\n');
+
+        // Format the away mean and standard deviation score
+        let mut away_mean_std_lines = String::from("Skill Diff\tMean Score\tStd Score");
+        for (diff, scores) in self.away_scores.iter() {
+            let mean = scores.clone().mean();
+            let std = scores.clone().std_dev();
+            let away_mean_std_line = format!("{}\t{:.4}\t{:.4}", diff, mean, std);
+            away_mean_std_lines = away_mean_std_lines + "\n" + &away_mean_std_line;
+        }
+        out.push_str("\nAway score distribution:\n");
+        out.push_str(&tabulate(&away_mean_std_lines));
+        out.push('\n');
+
+        // Format the observed score frequency
+        let num_scores: u32 = self.score_freq.values().sum();
+        let mut score_freq_table_lines = String::from("Score\tFrequency\tCount");
+        for (score, count) in self.score_freq.iter() {
+            let freq = *count as f64 / num_scores as f64;
+            let score_freq_table_line = format!("{}\t{:.4}%\t{}", score, freq * 100_f64, count);
+            score_freq_table_lines = score_freq_table_lines + "\n" + &score_freq_table_line;
+        }
+        out.push_str("\nScore frequency:\n");
+        out.push_str(&tabulate(&score_freq_table_lines));
+        out.push('\n');
+        out
+    }
+
+    /// Summarize the benchmark as a machine-readable report
+    pub fn report(&self, grid: &SkillGrid) -> BenchmarkReport {
+        BenchmarkReport{
+            benchmark: String::from(self.benchmark),
+            iterations: self.iterations,
+            skill_step: grid.step(),
+            matrices: vec![
+                BenchmarkMatrix::from_counts("win_probabilities", grid, &self.wins, self.iterations),
+                BenchmarkMatrix::from_counts("tie_probabilities", grid, &self.ties, self.iterations)
+            ],
+            distributions: vec![
+                BenchmarkDistribution::from_samples("home_score", "skill_diff", &self.home_scores),
+                BenchmarkDistribution::from_samples("away_score", "skill_diff", &self.away_scores),
+                BenchmarkDistribution::from_counts("score_frequency", "score", &self.score_freq)
+            ]
+        }
+    }
+}
+
+/// A machine-readable summary of a benchmark
+///
+/// Every cell of a report is summarized by its sample count, mean and
+/// standard deviation so that two reports can be compared statistically.
+/// Proportions are summarized as the mean of 0/1 samples.
+#[derive(Serialize, Deserialize)]
+pub struct BenchmarkReport {
+    pub benchmark: String,
+    pub iterations: usize,
+    pub skill_step: u32,
+    pub matrices: Vec<BenchmarkMatrix>,
+    pub distributions: Vec<BenchmarkDistribution>
+}
+
+/// A table of proportions over the cells of a skill grid
+///
+/// Rows are indexed by the away defense and columns by the home defense.
+#[derive(Serialize, Deserialize)]
+pub struct BenchmarkMatrix {
+    pub name: String,
+    pub defense_levels: Vec<u32>,
+    pub count: usize,
+    pub values: Vec<Vec<f64>>
+}
+
+impl BenchmarkMatrix {
+    /// Summarize per-cell counts out of the given number of iterations
+    pub fn from_counts(name: &str, grid: &SkillGrid, counts: &[Vec<u32>], iterations: usize) -> BenchmarkMatrix {
+        BenchmarkMatrix{
+            name: String::from(name),
+            defense_levels: (0..grid.len()).map(|i| grid.defense(i)).collect(),
+            count: iterations,
+            values: counts.iter().map(
+                |row| row.iter().map(|x| *x as f64 / iterations as f64).collect()
+            ).collect()
+        }
+    }
+}
+
+/// A distribution of sampled values grouped by a key
+#[derive(Serialize, Deserialize)]
+pub struct BenchmarkDistribution {
+    pub name: String,
+    pub group: String,
+    pub rows: Vec<BenchmarkRow>
+}
+
+/// The summary statistics of one group of a distribution
+#[derive(Serialize, Deserialize)]
+pub struct BenchmarkRow {
+    pub key: i64,
+    pub count: usize,
+    pub mean: f64,
+    pub std: f64
+}
+
+impl BenchmarkDistribution {
+    /// Summarize sampled values grouped by key
+    pub fn from_samples<K: Copy + Into<i64>>(name: &str, group: &str, samples: &BTreeMap<K, Vec<f64>>) -> BenchmarkDistribution {
+        let rows = samples.iter().map(
+            |(key, values)| BenchmarkRow{
+                key: (*key).into(),
+                count: values.len(),
+                mean: finite_or_zero(values.clone().mean()),
+                std: finite_or_zero(values.clone().std_dev())
+            }
+        ).collect();
+        BenchmarkDistribution{
+            name: String::from(name),
+            group: String::from(group),
+            rows
+        }
+    }
+
+    /// Summarize the frequency of each key among counted observations
+    pub fn from_counts<K: Copy + Into<i64>>(name: &str, group: &str, counts: &BTreeMap<K, u32>) -> BenchmarkDistribution {
+        let total: u32 = counts.values().sum();
+        let rows = counts.iter().map(
+            |(key, count)| {
+                let freq = if total > 0 { *count as f64 / total as f64 } else { 0_f64 };
+                BenchmarkRow{
+                    key: (*key).into(),
+                    count: total as usize,
+                    mean: freq,
+                    std: (freq * (1_f64 - freq)).sqrt()
+                }
+            }
+        ).collect();
+        BenchmarkDistribution{
+            name: String::from(name),
+            group: String::from(group),
+            rows
+        }
+    }
+}
+
+/// A single comparable cell of a benchmark report
+pub struct BenchmarkCell {
+    pub label: String,
+    pub count: usize,
+    pub mean: f64,
+    pub std: f64
+}
+
+impl BenchmarkReport {
+    /// Flatten the matrices and distributions of the report into cells
+    pub fn cells(&self) -> Vec<BenchmarkCell> {
+        let mut cells: Vec<BenchmarkCell> = Vec::new();
+        for matrix in self.matrices.iter() {
+            for (i, row) in matrix.values.iter().enumerate() {
+                for (j, prop) in row.iter().enumerate() {
+                    cells.push(
+                        BenchmarkCell{
+                            label: format!(
+                                "{}[away_defense={},home_defense={}]",
+                                matrix.name, matrix.defense_levels[i], matrix.defense_levels[j]
+                            ),
+                            count: matrix.count,
+                            mean: *prop,
+                            std: (prop * (1_f64 - prop)).sqrt()
+                        }
+                    );
+                }
+            }
+        }
+        for dist in self.distributions.iter() {
+            for row in dist.rows.iter() {
+                cells.push(
+                    BenchmarkCell{
+                        label: format!("{}[{}={}]", dist.name, dist.group, row.key),
+                        count: row.count,
+                        mean: row.mean,
+                        std: row.std
+                    }
+                );
+            }
+        }
+        cells
+    }
+
+    /// Format the report as CSV with one row per cell
+    pub fn to_csv(&self) -> String {
+        let mut lines: Vec<String> = vec![String::from("table,group,row,column,count,mean,std")];
+        for matrix in self.matrices.iter() {
+            for (i, row) in matrix.values.iter().enumerate() {
+                for (j, prop) in row.iter().enumerate() {
+                    lines.push(
+                        format!(
+                            "{},defense,{},{},{},{},{}",
+                            matrix.name, matrix.defense_levels[i], matrix.defense_levels[j],
+                            matrix.count, prop, (prop * (1_f64 - prop)).sqrt()
+                        )
+                    );
+                }
+            }
+        }
+        for dist in self.distributions.iter() {
+            for row in dist.rows.iter() {
+                lines.push(
+                    format!(
+                        "{},{},{},,{},{},{}",
+                        dist.name, dist.group, row.key, row.count, row.mean, row.std
+                    )
+                );
+            }
+        }
+        lines.join("\n") + "\n"
+    }
+}
+
+/// Write benchmark results in the given output format
+///
+/// The default output format is the aligned tables, otherwise the report is
+/// serialized as JSON or CSV. The output is written to the given file if
+/// one was passed, otherwise to stdout.
+pub fn write_benchmark_output<T, R>(output_format: &Option<String>, output_file: &Option<String>, tables: T, report: R) -> Result<(), String>
+where
+    T: FnOnce() -> String,
+    R: FnOnce() -> BenchmarkReport
+{
+    let output_format = OutputFormat::from_str(
+        &output_format.clone().unwrap_or(String::from(""))
+    ).unwrap();
+    let output_str: String = match output_format {
+        OutputFormat::Json => match serde_json::to_string_pretty(&report()) {
+            Ok(s) => s + "\n",
+            Err(e) => return Err(format!("Error serializing benchmark report: {}", e))
+        },
+        OutputFormat::Csv => report().to_csv(),
+        OutputFormat::Default => tables()
+    };
+    match output_file {
+        Some(x) => {
+            if let Err(e) = fs::write(x, output_str) {
+                return Err(format!("Error writing benchmark output file: {}", e));
+            }
+        },
+        None => print!("{}", output_str)
+    };
+    Ok(())
+}
+
+/// Replace the NaN statistics of empty or single-sample groups with zero
+fn finite_or_zero(x: f64) -> f64 {
+    if x.is_finite() { x } else { 0_f64 }
+}
+
+/// Align a tab-separated table into columns
+pub fn tabulate(lines: &str) -> String {
+    let mut tw = TabWriter::new(Vec::new());
+    write!(&mut tw, "{}", lines).unwrap();
+    tw.flush().unwrap();
+    String::from_utf8(tw.into_inner().unwrap()).unwrap()
+}
+
+/// Format the sample standard deviation of some samples, or `-` if there are
+/// too few samples for it to be defined
+pub fn format_std_dev(samples: &[f64]) -> String {
+    if samples.len() < 2 {
+        return String::from("-");
+    }
+    format!("{:.4}", samples.std_dev())
+}
+
+/// Format a table of the proportion of games in each grid cell
+///
+/// Rows are labelled by the away defense and columns by the home defense at
+/// either end of the grid.
+fn prop_table(grid: &SkillGrid, counts: &[Vec<u32>], iterations: usize) -> String {
+    let last = grid.len() - 1;
+    let mut header: Vec<String> = vec![String::new(); grid.len() + 1];
+    header[1] = format!("{}", grid.defense(0));
+    header[last + 1] = format!("{}", grid.defense(last));
+    let mut table_lines = header.join("\t");
+    for (i, row) in counts.iter().enumerate() {
+        let table_line = row.iter()
+            .map(|x| format!("{:.4}", *x as f64 / iterations as f64))
+            .collect::<Vec<_>>()
+            .join("\t");
+        let table_line_pfx = if i == 0 || i == last {
+            format!("{}", grid.defense(i))
+        } else {
+            String::from("")
+        };
+        table_lines = table_lines + "\n" + &table_line_pfx + "\t" + &table_line;
+    }
+    table_lines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_std_dev() {
+        assert_eq!(format_std_dev(&[]), "-");
+        assert_eq!(format_std_dev(&[21.0]), "-");
+        assert_eq!(format_std_dev(&[20.0, 24.0]), "2.8284");
+    }
+}
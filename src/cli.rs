@@ -1,4 +1,5 @@
 pub mod fbsim;
 pub mod game;
 pub mod league;
-pub mod output;
\ No newline at end of file
+pub mod output;
+pub mod team;
\ No newline at end of file
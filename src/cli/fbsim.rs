@@ -1,7 +1,8 @@
-use clap::{Parser, Subcommand};
+use clap::{ArgAction, Parser, Subcommand};
 
 use crate::cli::game::FbsimGameSubcommand;
 use crate::cli::league::FbsimLeagueSubcommand;
+use crate::cli::team::FbsimTeamSubcommand;
 
 /// fbsim command-line interface
 ///
@@ -11,6 +12,18 @@ use crate::cli::league::FbsimLeagueSubcommand;
 #[command(author="whatsacomputertho")]
 #[command(version, about, long_about = None)] // Read from `Cargo.toml`
 pub struct FbsimCli {
+    /// Seed for the simulation RNG, for reproducible results
+    #[arg(long="seed")]
+    #[arg(global=true)]
+    pub seed: Option<u64>,
+
+    /// Fail immediately if another command is using a league, rather than
+    /// waiting for it to release the league
+    #[arg(long="no-wait")]
+    #[arg(global=true)]
+    #[arg(action=ArgAction::SetFalse)]
+    pub wait: bool,
+
     /// The subcommand passed in via the CLI
     #[command(subcommand)]
     pub command: FbsimSubcommand
@@ -20,6 +33,14 @@ impl FbsimCli {
     pub fn command(&self) -> FbsimSubcommand {
         self.command.clone()
     }
+
+    pub fn seed(&self) -> Option<u64> {
+        self.seed
+    }
+
+    pub fn wait(&self) -> bool {
+        self.wait
+    }
 }
 
 /// The subcommands of the fbsim CLI
@@ -32,5 +53,9 @@ pub enum FbsimSubcommand {
     League {
         #[command(subcommand)]
         command: FbsimLeagueSubcommand
+    },
+    Team {
+        #[command(subcommand)]
+        command: FbsimTeamSubcommand
     }
 }
@@ -1,25 +1,172 @@
+pub mod context;
 pub mod drive;
 pub mod score;
 pub mod play;
 
 use clap::{Args, Subcommand};
 
+use crate::cli::game::context::FbsimGameContextSubcommand;
 use crate::cli::game::drive::FbsimGameDriveSubcommand;
 use crate::cli::game::play::FbsimGamePlaySubcommand;
 use crate::cli::game::score::FbsimGameScoreSubcommand;
 
+/// The arguments selecting a game's home and away teams, either from team
+/// files or by ID from the seasons of a league
+#[derive(Args, Clone)]
+pub struct FbsimGameTeamsArgs {
+    /// A path to a file specifying the game's home team
+    #[arg(long="home")]
+    #[arg(required_unless_present="home_id")]
+    #[arg(conflicts_with="home_id")]
+    pub home: Option<String>,
+
+    /// A path to a file specifying the game's away team
+    #[arg(long="away")]
+    #[arg(required_unless_present="away_id")]
+    #[arg(conflicts_with="away_id")]
+    pub away: Option<String>,
+
+    /// A path to a league to take the teams from by ID
+    #[arg(long="league")]
+    pub league: Option<String>,
+
+    /// The league ID of the game's home team
+    #[arg(long="home-id")]
+    #[arg(requires="league")]
+    pub home_id: Option<usize>,
+
+    /// The league ID of the game's away team
+    #[arg(long="away-id")]
+    #[arg(requires="league")]
+    pub away_id: Option<usize>,
+
+    /// The season to take the teams from (default the current season)
+    #[arg(long="year")]
+    #[arg(requires="league")]
+    pub year: Option<usize>,
+
+    /// The season to take the home team from, overriding --year
+    #[arg(long="home-year")]
+    #[arg(requires="home_id")]
+    pub home_year: Option<usize>,
+
+    /// The season to take the away team from, overriding --year
+    #[arg(long="away-year")]
+    #[arg(requires="away_id")]
+    pub away_year: Option<usize>,
+}
+
 /// The sim subcommand arguments
 #[derive(Args, Clone)]
 pub struct FbsimGameSimArgs {
+    /// The format to output
+    #[arg(short='o')]
+    #[arg(long="output")]
+    pub output_format: Option<String>,
+
+    /// The file to write to
+    #[arg(short='f')]
+    #[arg(long="file")]
+    pub output_file: Option<String>,
+
+    /// Simulate the game without playing it back
+    #[arg(long="no-playback")]
+    #[arg(conflicts_with="tui")]
+    pub no_playback: bool,
+
     /// The playback speed
     #[arg(short='s')]
     #[arg(long="speed")]
     pub playback_speed: Option<f64>,
 
+    /// Display the game in a full-screen scoreboard
+    #[arg(long="tui")]
+    pub tui: bool,
+
     /// Whether this is a neutral site game
     #[arg(long="neutral-site")]
     pub neutral_site: Option<bool>,
 
+    #[command(flatten)]
+    pub teams: FbsimGameTeamsArgs,
+
+    /// A path to a file to record the simulated game to
+    #[arg(long="record")]
+    pub record: Option<String>,
+
+    /// A path to a CSV file to export the play-by-play to
+    #[arg(long="pbp-csv")]
+    pub pbp_csv: Option<String>,
+
+    /// A path to an NDJSON file to export the play-by-play to
+    #[arg(long="pbp-ndjson")]
+    pub pbp_ndjson: Option<String>,
+
+    /// Display the game's drives as a chart over the field
+    #[arg(long="drive-chart")]
+    pub drive_chart: bool,
+}
+
+/// The coach subcommand arguments
+#[derive(Args, Clone)]
+pub struct FbsimGameCoachArgs {
+    /// Whether this is a neutral site game
+    #[arg(long="neutral-site")]
+    pub neutral_site: Option<bool>,
+
+    /// A path to a file specifying the game's home team
+    #[arg(long="home")]
+    pub home: String,
+
+    /// A path to a file specifying the game's away team
+    #[arg(long="away")]
+    pub away: String,
+
+    /// The team whose plays are called by the user (home or away)
+    #[arg(long="control")]
+    pub control: String,
+}
+
+/// The replay subcommand arguments
+#[derive(Args, Clone)]
+pub struct FbsimGameReplayArgs {
+    /// The playback speed
+    #[arg(short='s')]
+    #[arg(long="speed")]
+    pub playback_speed: Option<f64>,
+
+    /// A path to a recorded game file
+    pub recording: String,
+}
+
+/// The series subcommand arguments
+#[derive(Args, Clone)]
+pub struct FbsimGameSeriesArgs {
+    /// The number of games to simulate
+    #[arg(short='g')]
+    #[arg(long="games")]
+    pub games: Option<usize>,
+
+    /// Whether this is a neutral site series
+    #[arg(long="neutral-site")]
+    pub neutral_site: Option<bool>,
+
+    /// A path to a file specifying the series' home team
+    #[arg(long="home")]
+    pub home: String,
+
+    /// A path to a file specifying the series' away team
+    #[arg(long="away")]
+    pub away: String,
+}
+
+/// The winprob subcommand arguments
+#[derive(Args, Clone)]
+pub struct FbsimGameWinprobArgs {
+    /// A path to a file specifying the game context to simulate from
+    #[arg(long="context")]
+    pub context: String,
+
     /// A path to a file specifying the game's home team
     #[arg(long="home")]
     pub home: String,
@@ -27,17 +174,119 @@ pub struct FbsimGameSimArgs {
     /// A path to a file specifying the game's away team
     #[arg(long="away")]
     pub away: String,
+
+    /// The number of times to simulate the remainder of the game (default 5000)
+    #[arg(short='n')]
+    #[arg(long="trials")]
+    pub trials: Option<usize>,
+
+    /// The confidence level of the win probability intervals (default 0.95)
+    #[arg(long="confidence")]
+    pub confidence: Option<f64>,
+}
+
+/// The decide subcommand arguments
+#[derive(Args, Clone)]
+pub struct FbsimGameDecideArgs {
+    /// A path to a file specifying the 4th down or end-of-half game context to decide from
+    #[arg(long="context")]
+    pub context: String,
+
+    /// A path to a file specifying the game's home team
+    #[arg(long="home")]
+    pub home: String,
+
+    /// A path to a file specifying the game's away team
+    #[arg(long="away")]
+    pub away: String,
+
+    /// The number of times to simulate the remainder of the game per option (default 2000)
+    #[arg(short='n')]
+    #[arg(long="trials")]
+    pub trials: Option<usize>,
+
+    /// The confidence level of the win probability intervals (default 0.95)
+    #[arg(long="confidence")]
+    pub confidence: Option<f64>,
+}
+
+/// The benchmark compare subcommand arguments
+#[derive(Args, Clone)]
+pub struct FbsimGameBenchmarkCompareArgs {
+    /// A path to a JSON benchmark report to compare against
+    #[arg(long="baseline")]
+    pub baseline: String,
+
+    /// A path to a JSON benchmark report to compare, rather than re-running the baseline's benchmark
+    #[arg(long="current")]
+    pub current: Option<String>,
+
+    /// The largest allowed change in a cell's mean, in pooled standard deviations (default 0.2)
+    #[arg(long="tolerance")]
+    pub tolerance: Option<f64>,
+
+    /// The significance level of the statistical test across all cells (default 0.05)
+    #[arg(long="alpha")]
+    pub alpha: Option<f64>,
+
+    /// The number of worker threads to split a re-run benchmark across (default 1)
+    #[arg(short='t')]
+    #[arg(long="threads")]
+    pub threads: Option<usize>,
+}
+
+/// The fbsim game benchmark subcommands
+#[derive(Subcommand, Clone)]
+pub enum FbsimGameBenchmarkSubcommand {
+    Compare(FbsimGameBenchmarkCompareArgs)
 }
 
 /// The benchmark subcommand arguments
 #[derive(Args, Clone)]
-pub struct FbsimGameBenchmarkArgs {}
+#[command(args_conflicts_with_subcommands = true)]
+pub struct FbsimGameBenchmarkArgs {
+    #[command(subcommand)]
+    pub command: Option<FbsimGameBenchmarkSubcommand>,
+
+    /// The format to output (json or csv)
+    #[arg(short='o')]
+    #[arg(long="output")]
+    pub output_format: Option<String>,
+
+    /// The file to write to
+    #[arg(short='f')]
+    #[arg(long="file")]
+    pub output_file: Option<String>,
+
+    /// The number of games to simulate per skill level pairing (default 1000)
+    #[arg(short='n')]
+    #[arg(long="iterations")]
+    pub iterations: Option<usize>,
+
+    /// The step between benchmarked skill levels (default 10)
+    #[arg(long="skill-step")]
+    pub skill_step: Option<u32>,
+
+    /// The number of worker threads to split the benchmark across (default 1)
+    #[arg(short='t')]
+    #[arg(long="threads")]
+    pub threads: Option<usize>,
+}
 
 /// The subcommands of the fbsim game command
 #[derive(Subcommand, Clone)]
 pub enum FbsimGameSubcommand {
     Benchmark(FbsimGameBenchmarkArgs),
+    Coach(FbsimGameCoachArgs),
+    Decide(FbsimGameDecideArgs),
     Sim(FbsimGameSimArgs),
+    Replay(FbsimGameReplayArgs),
+    Series(FbsimGameSeriesArgs),
+    Winprob(FbsimGameWinprobArgs),
+    Context {
+        #[command(subcommand)]
+        command: FbsimGameContextSubcommand
+    },
     Play {
         #[command(subcommand)]
         command: FbsimGamePlaySubcommand
@@ -0,0 +1,82 @@
+use clap::{Args, Subcommand};
+
+/// The game context fields which may be set from the command line
+#[derive(Args, Clone)]
+pub struct FbsimGameContextFieldArgs {
+    /// The home team's short name
+    #[arg(long="home-short")]
+    pub home_short: Option<String>,
+
+    /// The away team's short name
+    #[arg(long="away-short")]
+    pub away_short: Option<String>,
+
+    /// The quarter, with quarters after the 4th being overtime
+    #[arg(long="quarter")]
+    pub quarter: Option<u32>,
+
+    /// The time left in the quarter (MM:SS)
+    #[arg(long="clock")]
+    pub clock: Option<String>,
+
+    /// The home team's score
+    #[arg(long="home-score")]
+    pub home_score: Option<u32>,
+
+    /// The away team's score
+    #[arg(long="away-score")]
+    pub away_score: Option<u32>,
+
+    /// The team in possession (home or away)
+    #[arg(long="possession")]
+    pub possession: Option<String>,
+
+    /// The down, setting a down means the next play is from scrimmage
+    #[arg(long="down")]
+    pub down: Option<u32>,
+
+    /// The distance to a first down
+    #[arg(long="distance")]
+    pub distance: Option<u32>,
+
+    /// The yard line relative to the team in possession (e.g. "OWN 25", "OPP 30" or "50")
+    #[arg(long="yard-line")]
+    pub yard_line: Option<String>,
+}
+
+/// The context new subcommand arguments
+#[derive(Args, Clone)]
+pub struct FbsimGameContextNewArgs {
+    /// The file to write to
+    #[arg(short='f')]
+    #[arg(long="file")]
+    pub output_file: Option<String>,
+
+    #[command(flatten)]
+    pub fields: FbsimGameContextFieldArgs,
+}
+
+/// The context set subcommand arguments
+#[derive(Args, Clone)]
+pub struct FbsimGameContextSetArgs {
+    /// A path to the game context file to update
+    pub context: String,
+
+    #[command(flatten)]
+    pub fields: FbsimGameContextFieldArgs,
+}
+
+/// The context show subcommand arguments
+#[derive(Args, Clone)]
+pub struct FbsimGameContextShowArgs {
+    /// A path to the game context file to display
+    pub context: String,
+}
+
+/// The fbsim game context subcommands
+#[derive(Subcommand, Clone)]
+pub enum FbsimGameContextSubcommand {
+    New(FbsimGameContextNewArgs),
+    Set(FbsimGameContextSetArgs),
+    Show(FbsimGameContextShowArgs)
+}
@@ -1,5 +1,7 @@
 use clap::{Args, Subcommand};
 
+use crate::cli::game::FbsimGameTeamsArgs;
+
 /// The sim subcommand arguments
 #[derive(Args, Clone)]
 pub struct FbsimGameDriveSimArgs {
@@ -21,17 +23,50 @@ pub struct FbsimGameDriveSimArgs {
     #[arg(long="update-context")]
     pub update_context: Option<bool>,
 
-    /// A path to a file specifying the game's home team
-    #[arg(long="home")]
-    pub home: String,
+    #[command(flatten)]
+    pub teams: FbsimGameTeamsArgs,
+
+    /// Keep simulating drives until end-of-quarter, end-of-half, score, possession-change or game-over
+    #[arg(long="until")]
+    pub until: Option<String>,
+
+    /// The most drives to simulate (default 1, or no limit with --until)
+    #[arg(short='n')]
+    #[arg(long="count")]
+    pub count: Option<usize>,
+}
+
+/// The benchmark subcommand arguments
+#[derive(Args, Clone)]
+pub struct FbsimGameDriveBenchmarkArgs {
+    /// The format to output (json or csv)
+    #[arg(short='o')]
+    #[arg(long="output")]
+    pub output_format: Option<String>,
+
+    /// The file to write to
+    #[arg(short='f')]
+    #[arg(long="file")]
+    pub output_file: Option<String>,
+
+    /// The number of drives to simulate per skill level and starting yard line (default 1000)
+    #[arg(short='n')]
+    #[arg(long="iterations")]
+    pub iterations: Option<usize>,
+
+    /// The step between benchmarked skill levels (default 10)
+    #[arg(long="skill-step")]
+    pub skill_step: Option<u32>,
 
-    /// A path to a file specifying the game's away team
-    #[arg(long="away")]
-    pub away: String,
+    /// The number of worker threads to split the benchmark across (default 1)
+    #[arg(short='t')]
+    #[arg(long="threads")]
+    pub threads: Option<usize>,
 }
 
 /// The fbsim game drive subcommands
 #[derive(Subcommand, Clone)]
 pub enum FbsimGameDriveSubcommand {
+    Benchmark(FbsimGameDriveBenchmarkArgs),
     Sim(FbsimGameDriveSimArgs)
 }
@@ -1,5 +1,7 @@
 use clap::{Args, Subcommand};
 
+use crate::cli::game::FbsimGameTeamsArgs;
+
 /// The sim subcommand arguments
 #[derive(Args, Clone)]
 pub struct FbsimGameScoreSimArgs {
@@ -13,18 +15,37 @@ pub struct FbsimGameScoreSimArgs {
     #[arg(long="file")]
     pub output_file: Option<String>,
 
-    /// A path to a file specifying the game's home team
-    #[arg(long="home")]
-    pub home: String,
-
-    /// A path to a file specifying the game's away team
-    #[arg(long="away")]
-    pub away: String,
+    #[command(flatten)]
+    pub teams: FbsimGameTeamsArgs,
 }
 
 /// The benchmark subcommand arguments
 #[derive(Args, Clone)]
-pub struct FbsimGameScoreBenchmarkArgs {}
+pub struct FbsimGameScoreBenchmarkArgs {
+    /// The format to output (json or csv)
+    #[arg(short='o')]
+    #[arg(long="output")]
+    pub output_format: Option<String>,
+
+    /// The file to write to
+    #[arg(short='f')]
+    #[arg(long="file")]
+    pub output_file: Option<String>,
+
+    /// The number of games to simulate per skill level pairing (default 10000)
+    #[arg(short='n')]
+    #[arg(long="iterations")]
+    pub iterations: Option<usize>,
+
+    /// The step between benchmarked skill levels (default 10)
+    #[arg(long="skill-step")]
+    pub skill_step: Option<u32>,
+
+    /// The number of worker threads to split the benchmark across (default 1)
+    #[arg(short='t')]
+    #[arg(long="threads")]
+    pub threads: Option<usize>,
+}
 
 /// The fbsim game score subcommands
 #[derive(Subcommand, Clone)]
@@ -13,12 +13,59 @@ pub struct FbsimLeagueCreateArgs {
     #[arg(short='f')]
     #[arg(long="file")]
     pub output_file: String,
+
+    /// The format to store the league in, json or sqlite (default json)
+    #[arg(long="store")]
+    pub store: Option<String>,
+}
+
+/// Convert a FootballSim league between the JSON and SQLite formats
+#[derive(Args, Clone)]
+pub struct FbsimLeagueConvertArgs {
+    /// The league file to convert
+    #[arg(short='l')]
+    #[arg(long="league")]
+    #[arg(env="FBSIM_LEAGUE")]
+    pub league: String,
+
+    /// The file to write the converted league to
+    #[arg(short='f')]
+    #[arg(long="file")]
+    pub output_file: String,
+
+    /// The format to convert to, json or sqlite (default the format the
+    /// league is not stored in)
+    #[arg(long="store")]
+    pub store: Option<String>,
+}
+
+/// Restore a FootballSim league to its snapshot before the last change
+#[derive(Args, Clone)]
+pub struct FbsimLeagueUndoArgs {
+    /// The input filepath for the league
+    #[arg(short='l')]
+    #[arg(long="league")]
+    #[arg(env="FBSIM_LEAGUE")]
+    pub league: String
+}
+
+/// List the snapshots in the history of a FootballSim league
+#[derive(Args, Clone)]
+pub struct FbsimLeagueHistoryArgs {
+    /// The input filepath for the league
+    #[arg(short='l')]
+    #[arg(long="league")]
+    #[arg(env="FBSIM_LEAGUE")]
+    pub league: String
 }
 
 /// Manage FootballSim leagues
 #[derive(Subcommand, Clone)]
 pub enum FbsimLeagueSubcommand {
     Create(FbsimLeagueCreateArgs),
+    Convert(FbsimLeagueConvertArgs),
+    Undo(FbsimLeagueUndoArgs),
+    History(FbsimLeagueHistoryArgs),
     Team {
         #[command(subcommand)]
         command: FbsimLeagueTeamSubcommand
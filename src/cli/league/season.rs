@@ -20,6 +20,7 @@ pub struct FbsimLeagueSeasonAddArgs {
     /// The input filepath for the league
     #[arg(short='l')]
     #[arg(long="league")]
+    #[arg(env="FBSIM_LEAGUE")]
     pub league: String
 }
 
@@ -29,6 +30,7 @@ pub struct FbsimLeagueSeasonGetArgs {
     /// The input filepath for the league
     #[arg(short='l')]
     #[arg(long="league")]
+    #[arg(env="FBSIM_LEAGUE")]
     pub league: String,
 
     /// The year of the season to display
@@ -43,6 +45,7 @@ pub struct FbsimLeagueSeasonListArgs {
     /// The input filepath for the league
     #[arg(short='l')]
     #[arg(long="league")]
+    #[arg(env="FBSIM_LEAGUE")]
     pub league: String
 }
 
@@ -52,6 +55,7 @@ pub struct FbsimLeagueSeasonSimArgs {
     /// The input filepath for the league
     #[arg(short='l')]
     #[arg(long="league")]
+    #[arg(env="FBSIM_LEAGUE")]
     pub league: String
 }
 
@@ -10,6 +10,7 @@ pub struct FbsimLeagueSeasonConferenceAddArgs {
     /// The input filepath for the league
     #[arg(short='l')]
     #[arg(long="league")]
+    #[arg(env="FBSIM_LEAGUE")]
     pub league: String,
 
     /// The name of the conference
@@ -24,6 +25,7 @@ pub struct FbsimLeagueSeasonConferenceListArgs {
     /// The input filepath for the league
     #[arg(short='l')]
     #[arg(long="league")]
+    #[arg(env="FBSIM_LEAGUE")]
     pub league: String,
 
     /// The year of the season
@@ -38,6 +40,7 @@ pub struct FbsimLeagueSeasonConferenceGetArgs {
     /// The input filepath for the league
     #[arg(short='l')]
     #[arg(long="league")]
+    #[arg(env="FBSIM_LEAGUE")]
     pub league: String,
 
     /// The year of the season
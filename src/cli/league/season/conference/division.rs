@@ -6,6 +6,7 @@ pub struct FbsimLeagueSeasonConferenceDivisionAddArgs {
     /// The input filepath for the league
     #[arg(short='l')]
     #[arg(long="league")]
+    #[arg(env="FBSIM_LEAGUE")]
     pub league: String,
 
     /// The conference index
@@ -25,6 +26,7 @@ pub struct FbsimLeagueSeasonConferenceDivisionListArgs {
     /// The input filepath for the league
     #[arg(short='l')]
     #[arg(long="league")]
+    #[arg(env="FBSIM_LEAGUE")]
     pub league: String,
 
     /// The year of the season
@@ -44,6 +46,7 @@ pub struct FbsimLeagueSeasonConferenceDivisionGetArgs {
     /// The input filepath for the league
     #[arg(short='l')]
     #[arg(long="league")]
+    #[arg(env="FBSIM_LEAGUE")]
     pub league: String,
 
     /// The year of the season
@@ -10,6 +10,7 @@ pub struct FbsimLeagueSeasonPlayoffsGenArgs {
     /// The input filepath for the league
     #[arg(short='l')]
     #[arg(long="league")]
+    #[arg(env="FBSIM_LEAGUE")]
     pub league: String,
 
     /// The number of teams in the playoffs (total, or per conference with -p flag)
@@ -34,6 +35,7 @@ pub struct FbsimLeagueSeasonPlayoffsGetArgs {
     /// The input filepath for the league
     #[arg(short='l')]
     #[arg(long="league")]
+    #[arg(env="FBSIM_LEAGUE")]
     pub league: String,
 
     /// The year of the season
@@ -48,6 +50,7 @@ pub struct FbsimLeagueSeasonPlayoffsSimArgs {
     /// The input filepath for the league
     #[arg(short='l')]
     #[arg(long="league")]
+    #[arg(env="FBSIM_LEAGUE")]
     pub league: String,
 }
 
@@ -57,6 +60,7 @@ pub struct FbsimLeagueSeasonPlayoffsPictureArgs {
     /// The input filepath for the league
     #[arg(short='l')]
     #[arg(long="league")]
+    #[arg(env="FBSIM_LEAGUE")]
     pub league: String,
 
     /// The year of the season
@@ -64,11 +68,10 @@ pub struct FbsimLeagueSeasonPlayoffsPictureArgs {
     #[arg(long="year")]
     pub year: usize,
 
-    /// Number of playoff teams (total, or per conference with -p flag)
+    /// Number of playoff teams (total, or per conference with -p flag, default 4)
     #[arg(short='n')]
     #[arg(long="num-playoff-teams")]
-    #[arg(default_value="4")]
-    pub num_playoff_teams: usize,
+    pub num_playoff_teams: Option<usize>,
 
     /// Calculate multi-conference playoff picture, where number of teams is per-conference
     #[arg(short='p')]
@@ -10,6 +10,7 @@ pub struct FbsimLeagueSeasonPlayoffsRoundGetArgs {
     /// The input filepath for the league
     #[arg(short='l')]
     #[arg(long="league")]
+    #[arg(env="FBSIM_LEAGUE")]
     pub league: String,
 
     /// The year of the season
@@ -39,6 +40,7 @@ pub struct FbsimLeagueSeasonPlayoffsRoundSimArgs {
     /// The input filepath for the league
     #[arg(short='l')]
     #[arg(long="league")]
+    #[arg(env="FBSIM_LEAGUE")]
     pub league: String,
 }
 
@@ -6,6 +6,7 @@ pub struct FbsimLeagueSeasonPlayoffsRoundMatchupGetArgs {
     /// The input filepath for the league
     #[arg(short='l')]
     #[arg(long="league")]
+    #[arg(env="FBSIM_LEAGUE")]
     pub league: String,
 
     /// The year of the season
@@ -32,6 +33,18 @@ pub struct FbsimLeagueSeasonPlayoffsRoundMatchupGetArgs {
     #[arg(short='w')]
     #[arg(long="winners-bracket")]
     pub winners_bracket: bool,
+
+    /// A path to a CSV file to export the play-by-play to
+    #[arg(long="pbp-csv")]
+    pub pbp_csv: Option<String>,
+
+    /// A path to an NDJSON file to export the play-by-play to
+    #[arg(long="pbp-ndjson")]
+    pub pbp_ndjson: Option<String>,
+
+    /// Display the game's drives as a chart over the field
+    #[arg(long="drive-chart")]
+    pub drive_chart: bool,
 }
 
 /// Simulate a matchup from a playoff round
@@ -45,6 +58,7 @@ pub struct FbsimLeagueSeasonPlayoffsRoundMatchupSimArgs {
     /// The input filepath for the league
     #[arg(short='l')]
     #[arg(long="league")]
+    #[arg(env="FBSIM_LEAGUE")]
     pub league: String,
 
     /// The playoff round index
@@ -67,6 +81,18 @@ pub struct FbsimLeagueSeasonPlayoffsRoundMatchupSimArgs {
     #[arg(short='w')]
     #[arg(long="winners-bracket")]
     pub winners_bracket: bool,
+
+    /// A path to a CSV file to export the play-by-play to
+    #[arg(long="pbp-csv")]
+    pub pbp_csv: Option<String>,
+
+    /// A path to an NDJSON file to export the play-by-play to
+    #[arg(long="pbp-ndjson")]
+    pub pbp_ndjson: Option<String>,
+
+    /// Display the game's drives as a chart over the field
+    #[arg(long="drive-chart")]
+    pub drive_chart: bool,
 }
 
 /// Manage matchups for a playoff round
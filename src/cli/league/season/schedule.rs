@@ -6,6 +6,7 @@ pub struct FbsimLeagueSeasonScheduleGenArgs {
     /// The input filepath for the league
     #[arg(short='l')]
     #[arg(long="league")]
+    #[arg(env="FBSIM_LEAGUE")]
     pub league: String,
 
     /// The number of weeks in the schedule
@@ -13,9 +14,10 @@ pub struct FbsimLeagueSeasonScheduleGenArgs {
     #[arg(long="weeks")]
     pub weeks: Option<usize>,
 
-    /// The schedule seed
+    /// Deprecated short form of the global --seed argument
+    #[arg(id="deprecated_seed")]
     #[arg(short='s')]
-    #[arg(long="seed")]
+    #[arg(hide=true)]
     pub seed: Option<u64>,
 
     /// How many places to shift the weeks of the schedule after generating it
@@ -6,6 +6,7 @@ pub struct FbsimLeagueSeasonStandingsArgs {
     /// The input filepath for the league
     #[arg(short='l')]
     #[arg(long="league")]
+    #[arg(env="FBSIM_LEAGUE")]
     pub league: String,
 
     /// The year of the season
@@ -10,6 +10,7 @@ pub struct FbsimLeagueSeasonTeamAddArgs {
     /// The input filepath for the league
     #[arg(short='l')]
     #[arg(long="league")]
+    #[arg(env="FBSIM_LEAGUE")]
     pub league: String,
 
     /// The input filepath for the team
@@ -29,6 +30,7 @@ pub struct FbsimLeagueSeasonTeamAssignArgs {
     /// The input filepath for the league
     #[arg(short='l')]
     #[arg(long="league")]
+    #[arg(env="FBSIM_LEAGUE")]
     pub league: String,
 
     /// The ID of the team to assign
@@ -53,6 +55,7 @@ pub struct FbsimLeagueSeasonTeamGetArgs {
     /// The input filepath for the league
     #[arg(short='l')]
     #[arg(long="league")]
+    #[arg(env="FBSIM_LEAGUE")]
     pub league: String,
 
     /// The ID of the team in the league
@@ -65,11 +68,10 @@ pub struct FbsimLeagueSeasonTeamGetArgs {
     #[arg(long="year")]
     pub year: usize,
 
-    /// Number of playoff teams (for playoff picture calculation)
+    /// Number of playoff teams (for playoff picture calculation, default 4)
     #[arg(short='n')]
     #[arg(long="num-playoff-teams")]
-    #[arg(default_value="4")]
-    pub num_playoff_teams: usize
+    pub num_playoff_teams: Option<usize>
 }
 
 /// List all teams from a FootballSim season
@@ -78,6 +80,7 @@ pub struct FbsimLeagueSeasonTeamListArgs {
     /// The input filepath for the league
     #[arg(short='l')]
     #[arg(long="league")]
+    #[arg(env="FBSIM_LEAGUE")]
     pub league: String,
 
     /// The year of the season
@@ -85,11 +88,10 @@ pub struct FbsimLeagueSeasonTeamListArgs {
     #[arg(long="year")]
     pub year: usize,
 
-    /// Number of playoff teams (for playoff picture calculation)
+    /// Number of playoff teams (for playoff picture calculation, default 4)
     #[arg(short='n')]
     #[arg(long="num-playoff-teams")]
-    #[arg(default_value="4")]
-    pub num_playoff_teams: usize
+    pub num_playoff_teams: Option<usize>
 }
 
 /// Manage teams for a season of a FootballSim league
@@ -6,6 +6,7 @@ pub struct FbsimLeagueSeasonTeamStatsPassingArgs {
     /// The input filepath for the league
     #[arg(short='l')]
     #[arg(long="league")]
+    #[arg(env="FBSIM_LEAGUE")]
     pub league: String,
 
     /// The year of the season
@@ -20,6 +21,7 @@ pub struct FbsimLeagueSeasonTeamStatsRushingArgs {
     /// The input filepath for the league
     #[arg(short='l')]
     #[arg(long="league")]
+    #[arg(env="FBSIM_LEAGUE")]
     pub league: String,
 
     /// The year of the season
@@ -34,6 +36,7 @@ pub struct FbsimLeagueSeasonTeamStatsReceivingArgs {
     /// The input filepath for the league
     #[arg(short='l')]
     #[arg(long="league")]
+    #[arg(env="FBSIM_LEAGUE")]
     pub league: String,
 
     /// The year of the season
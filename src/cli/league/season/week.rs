@@ -10,6 +10,7 @@ pub struct FbsimLeagueSeasonWeekGetArgs {
     /// The input filepath for the league
     #[arg(short='l')]
     #[arg(long="league")]
+    #[arg(env="FBSIM_LEAGUE")]
     pub league: String,
 
     /// The year of the season
@@ -29,6 +30,7 @@ pub struct FbsimLeagueSeasonWeekSimArgs {
     /// The input filepath for the league
     #[arg(short='l')]
     #[arg(long="league")]
+    #[arg(env="FBSIM_LEAGUE")]
     pub league: String,
 
     /// The ID of the week in the season
@@ -43,6 +45,7 @@ pub struct FbsimLeagueSeasonWeekListArgs {
     /// The input filepath for the league
     #[arg(short='l')]
     #[arg(long="league")]
+    #[arg(env="FBSIM_LEAGUE")]
     pub league: String,
 
     /// The year of the season
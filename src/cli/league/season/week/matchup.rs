@@ -10,6 +10,7 @@ pub struct FbsimLeagueSeasonWeekMatchupGetArgs {
     /// The input filepath for the league
     #[arg(short='l')]
     #[arg(long="league")]
+    #[arg(env="FBSIM_LEAGUE")]
     pub league: String,
 
     /// The year of the season
@@ -26,6 +27,18 @@ pub struct FbsimLeagueSeasonWeekMatchupGetArgs {
     #[arg(short='m')]
     #[arg(long="matchup")]
     pub matchup: usize,
+
+    /// A path to a CSV file to export the play-by-play to
+    #[arg(long="pbp-csv")]
+    pub pbp_csv: Option<String>,
+
+    /// A path to an NDJSON file to export the play-by-play to
+    #[arg(long="pbp-ndjson")]
+    pub pbp_ndjson: Option<String>,
+
+    /// Display the game's drives as a chart over the field
+    #[arg(long="drive-chart")]
+    pub drive_chart: bool,
 }
 
 /// Simulate a matchup from a week of a FootballSim season
@@ -36,9 +49,14 @@ pub struct FbsimLeagueSeasonWeekMatchupSimArgs {
     #[arg(long="speed")]
     pub playback_speed: Option<f64>,
 
+    /// Display the game in a full-screen scoreboard
+    #[arg(long="tui")]
+    pub tui: bool,
+
     /// The input filepath for the league
     #[arg(short='l')]
     #[arg(long="league")]
+    #[arg(env="FBSIM_LEAGUE")]
     pub league: String,
 
     /// The ID of the week in the season
@@ -50,6 +68,18 @@ pub struct FbsimLeagueSeasonWeekMatchupSimArgs {
     #[arg(short='m')]
     #[arg(long="matchup")]
     pub matchup: usize,
+
+    /// A path to a CSV file to export the play-by-play to
+    #[arg(long="pbp-csv")]
+    pub pbp_csv: Option<String>,
+
+    /// A path to an NDJSON file to export the play-by-play to
+    #[arg(long="pbp-ndjson")]
+    pub pbp_ndjson: Option<String>,
+
+    /// Display the game's drives as a chart over the field
+    #[arg(long="drive-chart")]
+    pub drive_chart: bool,
 }
 
 /// Manage matchups for a week of a FootballSim season
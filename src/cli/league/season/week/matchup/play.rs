@@ -6,6 +6,7 @@ pub struct FbsimLeagueSeasonWeekMatchupPlaySimArgs {
     /// The input filepath for the league
     #[arg(short='l')]
     #[arg(long="league")]
+    #[arg(env="FBSIM_LEAGUE")]
     pub league: String,
 
     /// The ID of the week in the season
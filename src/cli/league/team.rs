@@ -10,6 +10,7 @@ pub struct FbsimLeagueTeamAddArgs {
     /// The input filepath for the league
     #[arg(short='l')]
     #[arg(long="league")]
+    #[arg(env="FBSIM_LEAGUE")]
     pub league: String
 }
 
@@ -19,6 +20,7 @@ pub struct FbsimLeagueTeamGetArgs {
     /// The input filepath for the league
     #[arg(short='l')]
     #[arg(long="league")]
+    #[arg(env="FBSIM_LEAGUE")]
     pub league: String,
 
     /// The ID of the team to display
@@ -33,6 +35,7 @@ pub struct FbsimLeagueTeamListArgs {
     /// The input filepath for the league
     #[arg(short='l')]
     #[arg(long="league")]
+    #[arg(env="FBSIM_LEAGUE")]
     pub league: String
 }
 
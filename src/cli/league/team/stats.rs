@@ -6,6 +6,7 @@ pub struct FbsimLeagueTeamStatsPassingArgs {
     /// The input filepath for the league
     #[arg(short='l')]
     #[arg(long="league")]
+    #[arg(env="FBSIM_LEAGUE")]
     pub league: String
 }
 
@@ -15,6 +16,7 @@ pub struct FbsimLeagueTeamStatsRushingArgs {
     /// The input filepath for the league
     #[arg(short='l')]
     #[arg(long="league")]
+    #[arg(env="FBSIM_LEAGUE")]
     pub league: String
 }
 
@@ -24,6 +26,7 @@ pub struct FbsimLeagueTeamStatsReceivingArgs {
     /// The input filepath for the league
     #[arg(short='l')]
     #[arg(long="league")]
+    #[arg(env="FBSIM_LEAGUE")]
     pub league: String
 }
 
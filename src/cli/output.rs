@@ -4,6 +4,7 @@ use std::str::FromStr;
 #[derive(Debug,PartialEq)]
 pub enum OutputFormat {
     Json,
+    Csv,
     Default
 }
 
@@ -12,6 +13,7 @@ impl FromStr for OutputFormat {
     fn from_str(input: &str) -> Result<OutputFormat, Self::Err> {
         match input {
             "json"      => Ok(OutputFormat::Json),
+            "csv"       => Ok(OutputFormat::Csv),
             _           => Ok(OutputFormat::Default),
         }
     }
@@ -21,6 +23,7 @@ impl std::fmt::Display for OutputFormat {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let fmt_str = match self {
             OutputFormat::Json => "json",
+            OutputFormat::Csv => "csv",
             OutputFormat::Default => "default"
         };
         f.write_str(fmt_str)
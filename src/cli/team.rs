@@ -0,0 +1,78 @@
+use clap::{Subcommand, Args};
+
+/// Validate team files
+#[derive(Args, Clone)]
+pub struct FbsimTeamValidateArgs {
+    /// Paths to the team files to validate
+    #[arg(required=true)]
+    pub files: Vec<String>
+}
+
+/// Compare two teams and preview their matchup
+#[derive(Args, Clone)]
+pub struct FbsimTeamCompareArgs {
+    /// A path to a file specifying the first team, the home team in the preview
+    pub team_a: String,
+
+    /// A path to a file specifying the second team, the away team in the preview
+    pub team_b: String,
+
+    /// The number of games to simulate for the matchup preview (default 1000)
+    #[arg(short='g')]
+    #[arg(long="games")]
+    pub games: Option<usize>,
+
+    /// Whether the matchup preview is at a neutral site
+    #[arg(long="neutral-site")]
+    pub neutral_site: Option<bool>,
+}
+
+/// Generate teams with the given overall ratings
+#[derive(Args, Clone)]
+pub struct FbsimTeamGenArgs {
+    /// The offensive overall rating of the generated teams
+    #[arg(long="overall-offense")]
+    pub overall_offense: u32,
+
+    /// The defensive overall rating of the generated teams
+    #[arg(long="overall-defense")]
+    pub overall_defense: u32,
+
+    /// The name of the generated team, numbered if generating many (default random)
+    #[arg(long="name")]
+    #[arg(requires="short")]
+    pub name: Option<String>,
+
+    /// The short name of the generated team, numbered if generating many
+    #[arg(long="short")]
+    #[arg(requires="name")]
+    pub short: Option<String>,
+
+    /// The largest random change to each individual skill level (default 0)
+    #[arg(long="jitter")]
+    pub jitter: Option<u32>,
+
+    /// The number of teams to generate (default 1)
+    #[arg(short='n')]
+    #[arg(long="count")]
+    pub count: Option<usize>,
+
+    /// The file to write a single generated team to
+    #[arg(short='f')]
+    #[arg(long="file")]
+    #[arg(conflicts_with="output_dir")]
+    pub output_file: Option<String>,
+
+    /// The directory to write each generated team to
+    #[arg(short='d')]
+    #[arg(long="dir")]
+    pub output_dir: Option<String>,
+}
+
+/// Manage FootballSim team files
+#[derive(Subcommand, Clone)]
+pub enum FbsimTeamSubcommand {
+    Compare(FbsimTeamCompareArgs),
+    Gen(FbsimTeamGenArgs),
+    Validate(FbsimTeamValidateArgs)
+}
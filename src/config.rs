@@ -0,0 +1,165 @@
+use std::env;
+use std::ffi::OsStr;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::cli::fbsim::FbsimSubcommand;
+use crate::cli::game::FbsimGameSubcommand;
+use crate::cli::league::FbsimLeagueSubcommand;
+use crate::cli::league::season::FbsimLeagueSeasonSubcommand;
+use crate::cli::league::season::playoffs::FbsimLeagueSeasonPlayoffsSubcommand;
+use crate::cli::league::season::playoffs::round::FbsimLeagueSeasonPlayoffsRoundSubcommand;
+use crate::cli::league::season::playoffs::round::matchup::FbsimLeagueSeasonPlayoffsRoundMatchupSubcommand;
+use crate::cli::league::season::schedule::FbsimLeagueSeasonScheduleSubcommand;
+use crate::cli::league::season::team::FbsimLeagueSeasonTeamSubcommand;
+use crate::cli::league::season::week::FbsimLeagueSeasonWeekSubcommand;
+use crate::cli::league::season::week::matchup::FbsimLeagueSeasonWeekMatchupSubcommand;
+
+use clap::Command;
+use serde::Deserialize;
+
+/// The name of the project config file
+const CONFIG_FILE: &str = ".fbsim.toml";
+
+/// The environment variable holding the default league path
+const LEAGUE_ENV: &str = "FBSIM_LEAGUE";
+
+/// The default schedule generation options in a project config file
+#[derive(Deserialize, Default)]
+#[serde(deny_unknown_fields)]
+pub struct FbsimScheduleConfig {
+    weeks: Option<usize>,
+    shift: Option<usize>,
+    permute: Option<bool>,
+    division_games: Option<usize>,
+    conference_games: Option<usize>,
+    cross_conference_games: Option<usize>
+}
+
+/// The defaults in a project config file, overridden by explicit flags
+#[derive(Deserialize, Default)]
+#[serde(deny_unknown_fields)]
+pub struct FbsimConfig {
+    league: Option<String>,
+    playback_speed: Option<f64>,
+    num_playoff_teams: Option<usize>,
+    #[serde(default)]
+    schedule: FbsimScheduleConfig
+}
+
+/// Find the project config file in the current directory or its ancestors
+fn find_config() -> Option<PathBuf> {
+    let dir = env::current_dir().ok()?;
+    dir.ancestors().map(|d| d.join(CONFIG_FILE)).find(|p| p.is_file())
+}
+
+/// Fill an unset argument from its config default, returning whether it was
+/// unset
+fn fill<T: Clone>(arg: &mut Option<T>, default: &Option<T>) -> bool {
+    if arg.is_none() {
+        *arg = default.clone();
+        return true;
+    }
+    false
+}
+
+impl FbsimConfig {
+    /// Load the project config file, or empty defaults if there is none
+    ///
+    /// A league path in the config file is relative to the config file.
+    pub fn load() -> Result<FbsimConfig, String> {
+        let path = match find_config() {
+            Some(path) => path,
+            None => return Ok(FbsimConfig::default()),
+        };
+        let file = match fs::read_to_string(&path) {
+            Ok(file) => file,
+            Err(e) => return Err(format!("Error loading config file {}: {}", path.display(), e)),
+        };
+        let mut config: FbsimConfig = match toml::from_str(&file) {
+            Ok(config) => config,
+            Err(e) => return Err(format!("Error loading config file {}: {}", path.display(), e)),
+        };
+        if let (Some(league), Some(dir)) = (&config.league, path.parent()) {
+            config.league = Some(dir.join(Path::new(league)).display().to_string());
+        }
+        Ok(config)
+    }
+
+    /// Use the config file's league as the default of every league path
+    /// argument of a command and its subcommands, below an explicit flag and
+    /// the environment
+    pub fn default_league(&self, command: Command) -> Command {
+        let league = match &self.league {
+            Some(league) => league,
+            None => return command,
+        };
+        let ids: Vec<String> = command.get_arguments()
+            .filter(|a| a.get_env() == Some(OsStr::new(LEAGUE_ENV)))
+            .map(|a| a.get_id().to_string())
+            .collect();
+        let subcommands: Vec<String> = command.get_subcommands()
+            .map(|c| c.get_name().to_string())
+            .collect();
+        let mut command = command;
+        for id in ids.into_iter() {
+            command = command.mut_arg(id, |a| a.default_value(league.clone()).required(false));
+        }
+        for name in subcommands.into_iter() {
+            command = command.mut_subcommand(name, |c| self.default_league(c));
+        }
+        command
+    }
+
+    /// Fill the arguments of a command which were not given with the config
+    /// file's defaults
+    ///
+    /// Returns whether any of them were not given, meaning the command uses
+    /// the config file.
+    pub fn apply(&self, command: &mut FbsimSubcommand) -> bool {
+        match command {
+            FbsimSubcommand::Game { command } => match command {
+                FbsimGameSubcommand::Sim(args) => fill(&mut args.playback_speed, &self.playback_speed),
+                FbsimGameSubcommand::Replay(args) => fill(&mut args.playback_speed, &self.playback_speed),
+                _ => false
+            },
+            FbsimSubcommand::League { command: FbsimLeagueSubcommand::Season { command } } => match command {
+                FbsimLeagueSeasonSubcommand::Week { command: FbsimLeagueSeasonWeekSubcommand::Matchup {
+                    command: FbsimLeagueSeasonWeekMatchupSubcommand::Sim(args)
+                } } => fill(&mut args.playback_speed, &self.playback_speed),
+                FbsimLeagueSeasonSubcommand::Playoffs { command } => match command {
+                    FbsimLeagueSeasonPlayoffsSubcommand::Picture(args) => {
+                        fill(&mut args.num_playoff_teams, &self.num_playoff_teams)
+                    },
+                    FbsimLeagueSeasonPlayoffsSubcommand::Round { command: FbsimLeagueSeasonPlayoffsRoundSubcommand::Matchup {
+                        command: FbsimLeagueSeasonPlayoffsRoundMatchupSubcommand::Sim(args)
+                    } } => fill(&mut args.playback_speed, &self.playback_speed),
+                    _ => false
+                },
+                FbsimLeagueSeasonSubcommand::Team { command } => match command {
+                    FbsimLeagueSeasonTeamSubcommand::Get(args) => {
+                        fill(&mut args.num_playoff_teams, &self.num_playoff_teams)
+                    },
+                    FbsimLeagueSeasonTeamSubcommand::List(args) => {
+                        fill(&mut args.num_playoff_teams, &self.num_playoff_teams)
+                    },
+                    _ => false
+                },
+                FbsimLeagueSeasonSubcommand::Schedule { command: FbsimLeagueSeasonScheduleSubcommand::Gen(args) } => {
+                    let schedule = &self.schedule;
+                    let filled = [
+                        fill(&mut args.weeks, &schedule.weeks),
+                        fill(&mut args.shift, &schedule.shift),
+                        fill(&mut args.permute, &schedule.permute),
+                        fill(&mut args.division_games, &schedule.division_games),
+                        fill(&mut args.conference_games, &schedule.conference_games),
+                        fill(&mut args.cross_conference_games, &schedule.cross_conference_games)
+                    ];
+                    filled.contains(&true)
+                },
+                _ => false
+            },
+            _ => false
+        }
+    }
+}
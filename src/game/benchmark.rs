@@ -0,0 +1,90 @@
+pub mod compare;
+
+use indicatif::ProgressBar;
+use rand::Rng;
+
+use fbsim_core::game::play::GameSimulator;
+use fbsim_core::game::context::{GameContext, GameContextBuilder};
+use fbsim_core::team::FootballTeam;
+
+use crate::benchmark::{run_grid, write_benchmark_output, ScoreBenchmark, SkillGrid};
+use crate::cli::game::FbsimGameBenchmarkArgs;
+use crate::rng::new_rng;
+
+/// Simulate full games across a skill grid and track the final scores
+pub fn sim_game_benchmark(grid: &SkillGrid, iterations: usize, threads: usize, seed: Option<u64>) -> Result<ScoreBenchmark, String> {
+    if iterations == 0 {
+        return Err(String::from("Number of iterations must be greater than 0"));
+    }
+
+    // Instantiate the RNG and a progress bar for benchmark progress
+    let mut rng = new_rng(seed)?;
+    let progress_bar = ProgressBar::new((grid.len() * grid.len() * iterations) as u64);
+
+    // Run many game simulations on each thread and track the observed
+    // win and tie proportions by skill differential
+    let results = run_grid(grid.cells(), threads, &mut rng, |cells, rng| {
+        let game_sim = GameSimulator::new();
+        let mut benchmark = ScoreBenchmark::new("game", grid, iterations, 1000);
+        for &(i, j) in cells {
+            // Create the home and away teams
+            let home_team = FootballTeam::from_overalls(
+                "Home Team",
+                "HOME",
+                grid.offense(i),
+                grid.defense(j)
+            ).unwrap();
+            let away_team = FootballTeam::from_overalls(
+                "Away Team",
+                "AWAY",
+                grid.offense(j),
+                grid.defense(i)
+            ).unwrap();
+            for _ in 0..iterations {
+                // Simulate the game
+                let home_opening_kickoff: bool = rng.gen::<bool>();
+                let context: GameContext = GameContextBuilder::new()
+                    .home_possession(!home_opening_kickoff)
+                    .home_positive_direction(!home_opening_kickoff)
+                    .home_opening_kickoff(home_opening_kickoff)
+                    .build()
+                    .unwrap();
+                let (_game, context) = match game_sim.sim(&home_team, &away_team, context, rng) {
+                    Ok((g, c)) => (g, c),
+                    Err(e) => return Err(format!("Error simulating game: {}", e))
+                };
+
+                // Track the observed final score
+                benchmark.record(grid, (i, j), context.home_score(), context.away_score());
+
+                // Increment the progress bar
+                progress_bar.inc(1);
+            }
+        }
+        Ok(benchmark)
+    })?;
+    progress_bar.finish();
+
+    // Merge the results of each thread
+    let mut benchmark = ScoreBenchmark::new("game", grid, iterations, 1000);
+    for result in results.into_iter() {
+        benchmark.merge(result);
+    }
+    Ok(benchmark)
+}
+
+pub fn game_benchmark(args: FbsimGameBenchmarkArgs, seed: Option<u64>) -> Result<(), String> {
+    // Load the benchmark arguments and run the benchmark
+    let iterations: usize = args.iterations.unwrap_or(1000);
+    let grid = SkillGrid::new(args.skill_step.unwrap_or(10))?;
+    let threads: usize = args.threads.unwrap_or(1);
+    let benchmark = sim_game_benchmark(&grid, iterations, threads, seed)?;
+
+    // Write the benchmark results in the given output format
+    write_benchmark_output(
+        &args.output_format,
+        &args.output_file,
+        || benchmark.tables(&grid),
+        || benchmark.report(&grid)
+    )
+}
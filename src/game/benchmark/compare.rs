@@ -0,0 +1,170 @@
+use std::fs;
+use std::collections::HashMap;
+
+use statrs::distribution::{ContinuousCDF, StudentsT};
+
+use crate::benchmark::{tabulate, BenchmarkCell, BenchmarkReport, SkillGrid};
+use crate::cli::game::FbsimGameBenchmarkCompareArgs;
+use crate::game::benchmark::sim_game_benchmark;
+use crate::game::drive::benchmark::sim_drive_benchmark;
+use crate::game::play::benchmark::sim_play_benchmark;
+use crate::game::score::benchmark::sim_final_score_benchmark;
+
+use serde_json;
+
+/// Load a JSON benchmark report from a file
+fn load_report(path: &str) -> Result<BenchmarkReport, String> {
+    let report_file = match fs::read_to_string(path) {
+        Ok(file) => file,
+        Err(e) => return Err(format!("Error loading benchmark report file {}: {}", path, e)),
+    };
+    match serde_json::from_str(&report_file) {
+        Ok(report) => Ok(report),
+        Err(e) => Err(format!("Error loading benchmark report {}: {}", path, e)),
+    }
+}
+
+/// Re-run the benchmark described by a baseline report
+fn rerun_benchmark(baseline: &BenchmarkReport, threads: usize, seed: Option<u64>) -> Result<BenchmarkReport, String> {
+    let grid = SkillGrid::new(baseline.skill_step)?;
+    let iterations = baseline.iterations;
+    match baseline.benchmark.as_str() {
+        "drive" => Ok(sim_drive_benchmark(&grid, iterations, threads, seed)?.report(&grid)),
+        "game" => Ok(sim_game_benchmark(&grid, iterations, threads, seed)?.report(&grid)),
+        "play" => Ok(sim_play_benchmark(&grid, iterations, threads, seed)?.report(&grid)),
+        "score" => Ok(sim_final_score_benchmark(&grid, iterations, threads, seed)?.report(&grid)),
+        _ => Err(format!("Unknown benchmark in baseline report: {}", baseline.benchmark))
+    }
+}
+
+/// The two-sided p-value of Welch's t-test for a difference in cell means
+///
+/// Returns `None` if either cell has too few samples to test.
+fn welch_p_value(baseline: &BenchmarkCell, current: &BenchmarkCell) -> Option<f64> {
+    if baseline.count < 2 || current.count < 2 {
+        return None;
+    }
+    let base_var = baseline.std.powi(2) / baseline.count as f64;
+    let curr_var = current.std.powi(2) / current.count as f64;
+    let diff = current.mean - baseline.mean;
+    let var = base_var + curr_var;
+    if var == 0_f64 {
+        return Some(if diff == 0_f64 { 1_f64 } else { 0_f64 });
+    }
+    let t = diff / var.sqrt();
+    let df = var.powi(2) / (
+        base_var.powi(2) / (baseline.count as f64 - 1_f64) +
+        curr_var.powi(2) / (current.count as f64 - 1_f64)
+    );
+    let dist = StudentsT::new(0_f64, 1_f64, df).ok()?;
+    Some(2_f64 * (1_f64 - dist.cdf(t.abs())))
+}
+
+pub fn game_benchmark_compare(args: FbsimGameBenchmarkCompareArgs, seed: Option<u64>) -> Result<(), String> {
+    // Load the comparison arguments
+    let tolerance: f64 = args.tolerance.unwrap_or(0.2);
+    if tolerance < 0_f64 {
+        return Err(format!("Tolerance must not be negative: {}", tolerance));
+    }
+    let alpha: f64 = args.alpha.unwrap_or(0.05);
+    if !(alpha > 0_f64 && alpha < 1_f64) {
+        return Err(format!("Significance level must be in range (0, 1): {}", alpha));
+    }
+    let threads: usize = args.threads.unwrap_or(1);
+
+    // Load the baseline report and either load or re-run the current report
+    let baseline = load_report(&args.baseline)?;
+    let current = match &args.current {
+        Some(path) => load_report(path)?,
+        None => rerun_benchmark(&baseline, threads, seed)?
+    };
+    if baseline.benchmark != current.benchmark {
+        return Err(
+            format!(
+                "Cannot compare a {} benchmark against a {} benchmark",
+                current.benchmark, baseline.benchmark
+            )
+        );
+    }
+    if baseline.skill_step != current.skill_step {
+        return Err(
+            format!(
+                "Cannot compare benchmarks with skill steps {} and {}",
+                current.skill_step, baseline.skill_step
+            )
+        );
+    }
+
+    // Compare each cell of the current report against the baseline, with the
+    // significance level Bonferroni-corrected for the number of cells
+    let baseline_cells: HashMap<String, BenchmarkCell> = baseline.cells()
+        .into_iter()
+        .map(|cell| (cell.label.clone(), cell))
+        .collect();
+    let current_cells = current.cells();
+    let cell_alpha = alpha / current_cells.len().max(1) as f64;
+    let mut num_compared: usize = 0;
+    let mut flagged_lines = String::from("Cell\tBaseline\tCurrent\tEffect Size\tp-value\tReason");
+    let mut num_flagged: usize = 0;
+    for cell in current_cells.iter() {
+        // Skip cells missing from the baseline or without samples
+        let base_cell = match baseline_cells.get(&cell.label) {
+            Some(c) => c,
+            None => continue
+        };
+        if base_cell.count == 0 || cell.count == 0 {
+            continue;
+        }
+        num_compared += 1;
+
+        // Measure the change in the cell's mean as a standardized mean
+        // difference, scaled by the pooled standard deviation of both cells
+        let diff = (cell.mean - base_cell.mean).abs();
+        let pooled_std = ((base_cell.std.powi(2) + cell.std.powi(2)) / 2_f64).sqrt();
+        let change = if pooled_std > 0_f64 {
+            diff / pooled_std
+        } else if diff > 0_f64 {
+            f64::INFINITY
+        } else {
+            0_f64
+        };
+        let p_value = welch_p_value(base_cell, cell);
+
+        // Flag the cell if it moved beyond the tolerance or failed the test
+        let mut reasons: Vec<&str> = Vec::new();
+        if change > tolerance {
+            reasons.push("tolerance");
+        }
+        if p_value.is_some_and(|p| p < cell_alpha) {
+            reasons.push("t-test");
+        }
+        if !reasons.is_empty() {
+            num_flagged += 1;
+            let p_value_str = match p_value {
+                Some(p) => format!("{:.4e}", p),
+                None => String::from("-")
+            };
+            let flagged_line = format!(
+                "{}\t{:.4}\t{:.4}\t{:.4}\t{}\t{}",
+                cell.label, base_cell.mean, cell.mean, change, p_value_str, reasons.join(", ")
+            );
+            flagged_lines = flagged_lines + "\n" + &flagged_line;
+        }
+    }
+
+    // Display the flagged cells and a summary
+    if num_flagged > 0 {
+        println!();
+        println!("Flagged cells:");
+        println!("{}", tabulate(&flagged_lines));
+    }
+    println!();
+    println!(
+        "{} of {} compared cells flagged ({} benchmark, tolerance {}, alpha {})",
+        num_flagged, num_compared, current.benchmark, tolerance, alpha
+    );
+    if num_flagged > 0 {
+        return Err(format!("{} benchmark cells regressed against the baseline", num_flagged));
+    }
+    Ok(())
+}
@@ -0,0 +1,354 @@
+use fbsim_core::game::context::GameContext;
+use fbsim_core::game::play::{Game, Play};
+use fbsim_core::game::play::context::PlayContext;
+use fbsim_core::game::play::result::{PlayResult, PlayTypeResult, ScoreResult};
+use fbsim_core::game::stat::OffensiveStats;
+
+use serde::Serialize;
+
+use crate::benchmark::tabulate;
+
+/// The yard line at which the red zone starts, measured from the offense's
+/// own goal line
+const RED_ZONE_YARD_LINE: u32 = 80;
+
+/// Whether a play is from scrimmage, rather than a kickoff or extra point
+pub fn scrimmage_play(play: &Play) -> bool {
+    !play.context().next_play_kickoff() && !play.context().next_play_extra_point()
+}
+
+/// The game clock elapsed by a play and the runoff before the next play, in
+/// seconds
+pub fn elapsed_seconds(play: &Play) -> u32 {
+    let context = play.context();
+    let next_context = play.post_play().next_context(&play.result().next_context(context));
+    if next_context.half_seconds() > context.half_seconds() {
+        // The clock was reset for a new half or overtime period
+        context.half_seconds()
+    } else {
+        context.half_seconds() - next_context.half_seconds()
+    }
+}
+
+/// Format a number of successes out of a number of attempts with its rate
+fn conversion_rate(successes: u32, attempts: u32) -> String {
+    if attempts == 0 {
+        return String::from("0/0");
+    }
+    format!(
+        "{}/{} ({:.1}%)",
+        successes, attempts, successes as f64 * 100_f64 / attempts as f64
+    )
+}
+
+/// The box score for one team in a game
+#[derive(Serialize, Default)]
+pub struct TeamBoxScore {
+    team: String,
+    score: u32,
+    stats: OffensiveStats,
+    linescore: Vec<u32>,
+    first_downs: u32,
+    third_down_attempts: u32,
+    third_down_conversions: u32,
+    fourth_down_attempts: u32,
+    fourth_down_conversions: u32,
+    red_zone_trips: u32,
+    red_zone_touchdowns: u32,
+    time_of_possession: u32,
+    plays: u32,
+    total_yards: i32,
+    turnovers: u32,
+    sacks: u32,
+    punts: u32,
+    punt_yards: i32
+}
+
+impl TeamBoxScore {
+    /// The average yards gained per offensive play
+    pub fn yards_per_play(&self) -> f64 {
+        if self.plays == 0 {
+            return 0_f64;
+        }
+        self.total_yards as f64 / self.plays as f64
+    }
+
+    /// The average gross distance of the team's punts
+    pub fn average_punt(&self) -> f64 {
+        if self.punts == 0 {
+            return 0_f64;
+        }
+        self.punt_yards as f64 / self.punts as f64
+    }
+
+    /// Record the offense's side of a play from scrimmage
+    fn record_offense_play(&mut self, play: &Play, red_zone_drive: &mut bool) {
+        let context = play.context();
+        let result = play.result();
+        self.time_of_possession += elapsed_seconds(play);
+        if PlayContext::from(context).yard_line() >= RED_ZONE_YARD_LINE && !*red_zone_drive {
+            *red_zone_drive = true;
+            self.red_zone_trips += 1;
+        }
+        match result {
+            PlayTypeResult::Run(_) | PlayTypeResult::Pass(_) => {
+                // Track the yards, first downs and conversions
+                let first_down = !result.turnover() && result.net_yards() >= context.distance() as i32;
+                self.plays += 1;
+                self.total_yards += result.net_yards();
+                if first_down {
+                    self.first_downs += 1;
+                }
+                if context.down() == 3 {
+                    self.third_down_attempts += 1;
+                    self.third_down_conversions += first_down as u32;
+                } else if context.down() == 4 {
+                    self.fourth_down_attempts += 1;
+                    self.fourth_down_conversions += first_down as u32;
+                }
+                if *red_zone_drive && result.offense_score() == ScoreResult::Touchdown {
+                    self.red_zone_touchdowns += 1;
+                }
+                if result.turnover() {
+                    self.turnovers += 1;
+                }
+                if let PlayTypeResult::Pass(r) = result {
+                    self.sacks += r.sack() as u32;
+                }
+            },
+            PlayTypeResult::QbKneel(r) => {
+                self.turnovers += r.fumble() as u32;
+            },
+            PlayTypeResult::QbSpike(r) => {
+                self.turnovers += (r.fumble() || r.interception()) as u32;
+            },
+            PlayTypeResult::Punt(r) => {
+                self.punts += 1;
+                self.punt_yards += r.punt_yards();
+            },
+            _ => ()
+        }
+    }
+
+    /// Format the team's passing, rushing and receiving stats as a line
+    fn stats_line(&self) -> String {
+        format!(
+            "{} stats | Passing: {} | Rushing: {} | Receiving: {}",
+            self.team,
+            self.stats.passing(),
+            self.stats.rushing(),
+            self.stats.receiving()
+        )
+    }
+}
+
+/// The box score for a game, computed from its plays
+#[derive(Serialize)]
+pub struct BoxScore {
+    home: TeamBoxScore,
+    away: TeamBoxScore
+}
+
+impl BoxScore {
+    /// Compute the box score for a game given its latest context
+    pub fn new(game: &Game, context: &GameContext) -> BoxScore {
+        let mut home = TeamBoxScore{
+            team: String::from(context.home_team_short()),
+            score: context.home_score(),
+            stats: game.home_stats(),
+            linescore: vec![0; 4],
+            ..Default::default()
+        };
+        let mut away = TeamBoxScore{
+            team: String::from(context.away_team_short()),
+            score: context.away_score(),
+            stats: game.away_stats(),
+            linescore: vec![0; 4],
+            ..Default::default()
+        };
+        for drive in game.drives().iter() {
+            let mut red_zone_drive = false;
+            for play in drive.plays().iter() {
+                // Track the points scored by each team in the play's quarter
+                let play_context = play.context();
+                let next_context = play.result().next_context(play_context);
+                let quarter = play_context.quarter().max(1) as usize;
+                for (team, before, after) in [
+                    (&mut home, play_context.home_score(), next_context.home_score()),
+                    (&mut away, play_context.away_score(), next_context.away_score())
+                ] {
+                    if team.linescore.len() < quarter {
+                        team.linescore.resize(quarter, 0);
+                    }
+                    team.linescore[quarter - 1] += after.saturating_sub(before);
+                }
+
+                // Track the offense and defense stats of the play
+                let (offense, defense) = if play_context.home_possession() {
+                    (&mut home, &mut away)
+                } else {
+                    (&mut away, &mut home)
+                };
+                if scrimmage_play(play) {
+                    offense.record_offense_play(play, &mut red_zone_drive);
+                }
+                match play.result() {
+                    PlayTypeResult::Punt(r) if r.fumble() => defense.turnovers += 1,
+                    PlayTypeResult::Kickoff(r) if r.fumble() && !r.onside_kick() => defense.turnovers += 1,
+                    _ => ()
+                }
+            }
+        }
+        BoxScore{
+            home,
+            away
+        }
+    }
+}
+
+impl std::fmt::Display for BoxScore {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        // Format the quarter-by-quarter linescore
+        let periods = self.home.linescore.len().max(self.away.linescore.len());
+        let mut linescore_lines = String::from("Team");
+        for period in 1..=periods {
+            if period <= 4 {
+                linescore_lines = linescore_lines + &format!("\t{}Q", period);
+            } else {
+                linescore_lines = linescore_lines + &format!("\t{}OT", period - 4);
+            }
+        }
+        linescore_lines += "\tFinal";
+        for team in [&self.away, &self.home] {
+            linescore_lines = linescore_lines + "\n" + &team.team;
+            for period in 0..periods {
+                let points = team.linescore.get(period).copied().unwrap_or_default();
+                linescore_lines = linescore_lines + &format!("\t{}", points);
+            }
+            linescore_lines = linescore_lines + &format!("\t{}", team.score);
+        }
+
+        // Format the team stats side by side
+        let (a, h) = (&self.away, &self.home);
+        let team_stats: [(&str, String, String); 11] = [
+            ("First downs", a.first_downs.to_string(), h.first_downs.to_string()),
+            (
+                "3rd down conversions",
+                conversion_rate(a.third_down_conversions, a.third_down_attempts),
+                conversion_rate(h.third_down_conversions, h.third_down_attempts)
+            ),
+            (
+                "4th down conversions",
+                conversion_rate(a.fourth_down_conversions, a.fourth_down_attempts),
+                conversion_rate(h.fourth_down_conversions, h.fourth_down_attempts)
+            ),
+            (
+                "Red zone touchdowns",
+                conversion_rate(a.red_zone_touchdowns, a.red_zone_trips),
+                conversion_rate(h.red_zone_touchdowns, h.red_zone_trips)
+            ),
+            ("Total yards", a.total_yards.to_string(), h.total_yards.to_string()),
+            ("Plays", a.plays.to_string(), h.plays.to_string()),
+            ("Yards per play", format!("{:.1}", a.yards_per_play()), format!("{:.1}", h.yards_per_play())),
+            ("Turnovers", a.turnovers.to_string(), h.turnovers.to_string()),
+            ("Sacks taken", a.sacks.to_string(), h.sacks.to_string()),
+            (
+                "Punts (average)",
+                format!("{} ({:.1})", a.punts, a.average_punt()),
+                format!("{} ({:.1})", h.punts, h.average_punt())
+            ),
+            (
+                "Time of possession",
+                format!("{}:{:02}", a.time_of_possession / 60, a.time_of_possession % 60),
+                format!("{}:{:02}", h.time_of_possession / 60, h.time_of_possession % 60)
+            )
+        ];
+        let mut team_stats_lines = format!("Team stats\t{}\t{}", a.team, h.team);
+        for (stat, away_stat, home_stat) in team_stats.iter() {
+            team_stats_lines = team_stats_lines + &format!("\n{}\t{}\t{}", stat, away_stat, home_stat);
+        }
+
+        f.write_str(
+            &format!(
+                "{}\n\n{}\n\n{}\n{}",
+                tabulate(&linescore_lines),
+                tabulate(&team_stats_lines),
+                h.stats_line(),
+                a.stats_line()
+            )
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use fbsim_core::game::context::GameContextBuilder;
+    use fbsim_core::game::play::GameSimulator;
+    use fbsim_core::team::FootballTeam;
+
+    use crate::rng::new_rng;
+
+    /// Simulate a game between two teams of the given overalls
+    fn sim_game(seed: u64, home_overall: u32, away_overall: u32) -> (Game, GameContext) {
+        let home = FootballTeam::from_overalls("Home Team", "HOME", home_overall, home_overall).unwrap();
+        let away = FootballTeam::from_overalls("Away Team", "AWAY", away_overall, away_overall).unwrap();
+        let context = GameContextBuilder::new()
+            .home_team_short("HOME")
+            .away_team_short("AWAY")
+            .build()
+            .unwrap();
+        let mut rng = new_rng(Some(seed)).unwrap();
+        GameSimulator::new().sim(&home, &away, context, &mut rng).unwrap()
+    }
+
+    #[test]
+    fn test_rates_without_plays() {
+        let team = TeamBoxScore::default();
+        assert_eq!(team.yards_per_play(), 0_f64);
+        assert_eq!(team.average_punt(), 0_f64);
+    }
+
+    #[test]
+    fn test_rates() {
+        let team = TeamBoxScore{
+            plays: 8,
+            total_yards: 50,
+            punts: 3,
+            punt_yards: 135,
+            ..Default::default()
+        };
+        assert_eq!(team.yards_per_play(), 6.25);
+        assert_eq!(team.average_punt(), 45_f64);
+    }
+
+    #[test]
+    fn test_conversion_rate() {
+        assert_eq!(conversion_rate(0, 0), "0/0");
+        assert_eq!(conversion_rate(3, 8), "3/8 (37.5%)");
+    }
+
+    #[test]
+    fn test_box_score_totals() {
+        for (seed, home_overall, away_overall) in [(1, 50, 50), (2, 90, 30), (3, 30, 90), (4, 70, 60)] {
+            let (game, context) = sim_game(seed, home_overall, away_overall);
+            let box_score = BoxScore::new(&game, &context);
+            let periods = box_score.home.linescore.len().max(box_score.away.linescore.len());
+            assert!(periods >= 4);
+            let mut time_of_possession = 0;
+            for team in [&box_score.home, &box_score.away] {
+                assert_eq!(team.linescore.iter().sum::<u32>(), team.score, "seed {}", seed);
+                assert!(team.third_down_conversions <= team.third_down_attempts);
+                assert!(team.fourth_down_conversions <= team.fourth_down_attempts);
+                assert!(team.red_zone_touchdowns <= team.red_zone_trips);
+                assert!(team.first_downs <= team.plays);
+                assert!(team.sacks <= team.plays);
+                time_of_possession += team.time_of_possession;
+            }
+            assert_eq!(box_score.home.score, context.home_score());
+            assert_eq!(box_score.away.score, context.away_score());
+            assert!(time_of_possession > 0 && time_of_possession <= 900 * periods as u32);
+        }
+    }
+}
@@ -0,0 +1,149 @@
+use std::io::{stdin, stdout, BufRead, Write};
+
+use rand::Rng;
+
+use fbsim_core::game::context::{GameContext, GameContextBuilder};
+use fbsim_core::game::play::{Play, PlaySimulator};
+use fbsim_core::game::play::result::{PlayResult, PlayResultSimulator, PlayTypeResult};
+use fbsim_core::game::play::result::betweenplay::BetweenPlayResultSimulator;
+use fbsim_core::game::play::result::fieldgoal::FieldGoalResultSimulator;
+use fbsim_core::game::play::result::pass::PassResultSimulator;
+use fbsim_core::game::play::result::punt::PuntResultSimulator;
+use fbsim_core::game::play::result::run::RunResultSimulator;
+use fbsim_core::team::FootballTeam;
+
+use crate::cli::game::FbsimGameCoachArgs;
+use crate::rng::new_rng;
+use crate::team::load::load_team;
+
+/// The play calls available to the user in coach mode
+pub enum CoachPlayCall {
+    Run,
+    Pass,
+    Punt,
+    FieldGoal
+}
+
+/// Prompt the user for a play call until a valid one is given
+fn prompt_play_call(context: &GameContext) -> Result<CoachPlayCall, String> {
+    let fourth_down = context.down() == 4;
+    let prompt = if fourth_down {
+        "Call the play: [g]o for it, [k] punt, [f]ield goal: "
+    } else {
+        "Call the play: [r]un, [p]ass, [k] punt, [f]ield goal: "
+    };
+    loop {
+        let call = read_input(prompt)?;
+        match call.as_str() {
+            "r" | "run" if !fourth_down => return Ok(CoachPlayCall::Run),
+            "p" | "pass" if !fourth_down => return Ok(CoachPlayCall::Pass),
+            "g" | "go" if fourth_down => loop {
+                let go_call = read_input("Go for it: [r]un, [p]ass: ")?;
+                match go_call.as_str() {
+                    "r" | "run" => return Ok(CoachPlayCall::Run),
+                    "p" | "pass" => return Ok(CoachPlayCall::Pass),
+                    _ => println!("Invalid play call: {}", go_call)
+                }
+            },
+            "k" | "punt" => return Ok(CoachPlayCall::Punt),
+            "f" | "field goal" => return Ok(CoachPlayCall::FieldGoal),
+            _ => println!("Invalid play call: {}", call)
+        }
+    }
+}
+
+/// Display a prompt and read a trimmed, lowercase line of user input
+fn read_input(prompt: &str) -> Result<String, String> {
+    print!("{}", prompt);
+    if stdout().flush().is_err() {
+        return Err(String::from("Failed to flush stdout"));
+    }
+    let mut input = String::new();
+    match stdin().lock().read_line(&mut input) {
+        Ok(0) => Err(String::from("No play call given, exiting coach mode")),
+        Ok(_) => Ok(input.trim().to_lowercase()),
+        Err(e) => Err(format!("Error reading play call: {}", e))
+    }
+}
+
+/// Simulate a play called by the user for the team in possession
+pub fn sim_called_play(
+    call: CoachPlayCall,
+    offense: &FootballTeam,
+    defense: &FootballTeam,
+    context: GameContext,
+    rng: &mut impl Rng
+) -> (Play, GameContext) {
+    let result = match call {
+        CoachPlayCall::Run => RunResultSimulator::new().sim(offense, defense, &context, rng),
+        CoachPlayCall::Pass => PassResultSimulator::new().sim(offense, defense, &context, rng),
+        CoachPlayCall::Punt => PuntResultSimulator::new().sim(offense, defense, &context, rng),
+        CoachPlayCall::FieldGoal => FieldGoalResultSimulator::new().sim(offense, defense, &context, rng)
+    };
+    let next_context = result.next_context(&context);
+    let between_res: PlayTypeResult = BetweenPlayResultSimulator::new().sim(offense, defense, &next_context, rng);
+    let new_context = between_res.next_context(&next_context);
+    (Play::new(context, result, between_res), new_context)
+}
+
+pub fn game_coach(args: FbsimGameCoachArgs, seed: Option<u64>) -> Result<(), String> {
+    // Load the home and away teams from their files
+    let home_team: FootballTeam = match load_team(&args.home) {
+        Ok(team) => team,
+        Err(e) => return Err(format!("Error loading home team: {}", e)),
+    };
+    let away_team: FootballTeam = match load_team(&args.away) {
+        Ok(team) => team,
+        Err(e) => return Err(format!("Error loading away team: {}", e)),
+    };
+
+    // Decide which team the user controls
+    let control_home: bool = match args.control.as_str() {
+        "home" => true,
+        "away" => false,
+        _ => return Err(format!("Invalid team to control, expected home or away: {}", args.control))
+    };
+
+    // Load the neutral site argument
+    let neutral_site: bool = args.neutral_site.unwrap_or(false);
+
+    // Initialize a new context and RNG
+    let mut rng = new_rng(seed)?;
+    let home_opening_kickoff: bool = rng.gen::<bool>();
+    let mut context: GameContext = GameContextBuilder::new()
+        .home_team_short(home_team.short_name())
+        .away_team_short(away_team.short_name())
+        .home_possession(!home_opening_kickoff)
+        .home_positive_direction(!home_opening_kickoff)
+        .home_opening_kickoff(home_opening_kickoff)
+        .neutral_site(neutral_site)
+        .build()
+        .unwrap();
+
+    // Simulate until the game is over, stopping before each snap of the
+    // controlled team for the user to call the play
+    let play_sim = PlaySimulator::new();
+    while !context.game_over() {
+        let user_snap = context.home_possession() == control_home &&
+            !(context.next_play_kickoff() || context.next_play_extra_point());
+        let (play, new_context) = if user_snap {
+            println!();
+            println!("{}", context);
+            let call = prompt_play_call(&context)?;
+            if context.home_possession() {
+                sim_called_play(call, &home_team, &away_team, context, &mut rng)
+            } else {
+                sim_called_play(call, &away_team, &home_team, context, &mut rng)
+            }
+        } else {
+            play_sim.sim(&home_team, &away_team, context, &mut rng)
+        };
+        println!("{}", play);
+        context = new_context;
+    }
+
+    // Print game-over message
+    println!();
+    println!("{} Game over", context);
+    Ok(())
+}
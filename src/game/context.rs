@@ -0,0 +1,301 @@
+use std::fs;
+
+use fbsim_core::game::context::{GameContext, GameContextBuilder};
+use fbsim_core::game::play::context::PlayContext;
+
+use crate::benchmark::tabulate;
+use crate::cli::game::context::{
+    FbsimGameContextFieldArgs,
+    FbsimGameContextNewArgs,
+    FbsimGameContextSetArgs,
+    FbsimGameContextShowArgs
+};
+
+use serde_json;
+
+/// Load a game context from a JSON file
+pub fn load_context(path: &str) -> Result<GameContext, String> {
+    let context_file = match fs::read_to_string(path) {
+        Ok(f) => f,
+        Err(e) => return Err(format!("Error loading context file: {}", e))
+    };
+    match serde_json::from_str(&context_file) {
+        Ok(c) => Ok(c),
+        Err(e) => Err(format!("Error loading context from file: {}", e))
+    }
+}
+
+/// Initialize a game context builder with every field of an existing context
+fn context_builder(context: &GameContext) -> GameContextBuilder {
+    GameContextBuilder::new()
+        .home_team_short(context.home_team_short())
+        .away_team_short(context.away_team_short())
+        .quarter(context.quarter())
+        .half_seconds(context.half_seconds())
+        .down(context.down())
+        .distance(context.distance())
+        .yard_line(context.yard_line())
+        .home_score(context.home_score())
+        .away_score(context.away_score())
+        .home_timeouts(context.home_timeouts())
+        .away_timeouts(context.away_timeouts())
+        .home_positive_direction(context.home_positive_direction())
+        .home_opening_kickoff(context.home_opening_kickoff())
+        .home_possession(context.home_possession())
+        .last_play_turnover(context.last_play_turnover())
+        .last_play_incomplete(context.last_play_incomplete())
+        .last_play_out_of_bounds(context.last_play_out_of_bounds())
+        .last_play_timeout(context.last_play_timeout())
+        .last_play_kickoff(context.last_play_kickoff())
+        .last_play_punt(context.last_play_punt())
+        .next_play_extra_point(context.next_play_extra_point())
+        .next_play_kickoff(context.next_play_kickoff())
+        .neutral_site(context.neutral_site())
+        .end_of_half(context.end_of_half())
+        .game_over(context.game_over())
+}
+
+/// The time left in the current quarter of a context, in seconds
+pub fn quarter_seconds(context: &GameContext) -> u32 {
+    let quarter = context.quarter();
+    if context.half_seconds() < 900 ||
+        (context.half_seconds() == 900 && quarter.is_multiple_of(2) && quarter <= 4) {
+        context.half_seconds()
+    } else {
+        context.half_seconds() - 900
+    }
+}
+
+/// Convert the time left in a quarter to the time left in the half
+fn half_seconds(quarter: u32, quarter_seconds: u32) -> u32 {
+    if quarter % 2 == 1 && quarter < 4 {
+        quarter_seconds + 900
+    } else {
+        quarter_seconds
+    }
+}
+
+/// Parse a game clock (MM:SS) into seconds
+fn parse_clock(clock: &str) -> Result<u32, String> {
+    let errmsg = format!("Invalid clock, expected MM:SS: {}", clock);
+    let (mins, secs) = match clock.split_once(':') {
+        Some((m, s)) => (m.trim(), s.trim()),
+        None => return Err(errmsg)
+    };
+    let mins: u32 = match mins.parse() {
+        Ok(m) => m,
+        Err(_) => return Err(errmsg)
+    };
+    let secs: u32 = match secs.parse() {
+        Ok(s) if s < 60 => s,
+        _ => return Err(errmsg)
+    };
+    let seconds = mins * 60 + secs;
+    if seconds > 900 {
+        return Err(format!("Clock is not in range [0:00, 15:00]: {}", clock));
+    }
+    Ok(seconds)
+}
+
+/// Parse a yard line relative to the offense into the yards to a touchdown
+fn parse_yard_line(yard_line: &str) -> Result<u32, String> {
+    let errmsg = format!("Invalid yard line, expected OWN n, OPP n or 50: {}", yard_line);
+    let upper = yard_line.trim().to_uppercase();
+    let (side, yards) = match upper.split_once(' ') {
+        Some((side, yards)) => (side.trim().to_string(), yards.trim().to_string()),
+        None => (String::new(), upper.clone())
+    };
+    let yards: u32 = match yards.parse() {
+        Ok(y) if y <= 50 => y,
+        _ => return Err(errmsg)
+    };
+    match side.as_str() {
+        "OWN" => Ok(100 - yards),
+        "OPP" => Ok(yards),
+        "" if yards == 50 => Ok(50),
+        _ => Err(errmsg)
+    }
+}
+
+/// Apply the field arguments to a context, returning the updated context
+fn apply_fields(context: &GameContext, fields: &FbsimGameContextFieldArgs) -> Result<GameContext, String> {
+    let mut builder = context_builder(context);
+
+    // Set the team short names and scores
+    if let Some(x) = &fields.home_short {
+        builder = builder.home_team_short(x);
+    }
+    if let Some(x) = &fields.away_short {
+        builder = builder.away_team_short(x);
+    }
+    if let Some(x) = fields.home_score {
+        builder = builder.home_score(x);
+    }
+    if let Some(x) = fields.away_score {
+        builder = builder.away_score(x);
+    }
+
+    // Set the quarter and clock, keeping the time left in the quarter if
+    // only the quarter was given
+    let quarter = fields.quarter.unwrap_or(context.quarter());
+    if quarter == 0 {
+        return Err(String::from("Quarter must be greater than 0"));
+    }
+    let clock = match &fields.clock {
+        Some(x) => parse_clock(x)?,
+        None => quarter_seconds(context)
+    };
+    builder = builder.quarter(quarter).half_seconds(half_seconds(quarter, clock));
+
+    // Set the possession
+    let home_possession = match fields.possession.as_deref() {
+        Some("home") => true,
+        Some("away") => false,
+        Some(x) => return Err(format!("Invalid possession, expected home or away: {}", x)),
+        None => context.home_possession()
+    };
+    builder = builder.home_possession(home_possession);
+
+    // Set the yard line from the perspective of the team in possession
+    if let Some(x) = &fields.yard_line {
+        let yards_to_touchdown = parse_yard_line(x)?;
+        let yard_line = if home_possession ^ context.home_positive_direction() {
+            yards_to_touchdown
+        } else {
+            100 - yards_to_touchdown
+        };
+        builder = builder.yard_line(yard_line);
+    }
+
+    // Set the down and distance, a down means the next play is from scrimmage
+    if let Some(x) = fields.down {
+        if !(1..=4).contains(&x) {
+            return Err(format!("Down is not in range [1, 4]: {}", x));
+        }
+        builder = builder.down(x)
+            .next_play_kickoff(false)
+            .next_play_extra_point(false);
+    }
+    if let Some(x) = fields.distance {
+        builder = builder.distance(x);
+    }
+    match builder.build() {
+        Ok(c) => Ok(c),
+        Err(e) => Err(format!("Error building game context: {}", e))
+    }
+}
+
+pub fn context_new(args: FbsimGameContextNewArgs) -> Result<(), String> {
+    // Build the context from the defaults and the given fields
+    let context = apply_fields(&GameContext::new(), &args.fields)?;
+    let context_str = match serde_json::to_string_pretty(&context) {
+        Ok(s) => s,
+        Err(e) => return Err(format!("Error serializing context: {}", e))
+    };
+
+    // Write the context either to stdout or to a file
+    match &args.output_file {
+        Some(x) => {
+            if let Err(e) = fs::write(x, context_str) {
+                return Err(format!("Error writing context file: {}", e));
+            }
+        },
+        None => println!("{}", context_str)
+    };
+    Ok(())
+}
+
+pub fn context_set(args: FbsimGameContextSetArgs) -> Result<(), String> {
+    // Load the context, apply the given fields and write it back
+    let context = load_context(&args.context)?;
+    let new_context = apply_fields(&context, &args.fields)?;
+    let context_str = match serde_json::to_string_pretty(&new_context) {
+        Ok(s) => s,
+        Err(e) => return Err(format!("Error serializing context: {}", e))
+    };
+    if let Err(e) = fs::write(&args.context, context_str) {
+        return Err(format!("Error writing context file: {}", e));
+    }
+    Ok(())
+}
+
+pub fn context_show(args: FbsimGameContextShowArgs) -> Result<(), String> {
+    let context = load_context(&args.context)?;
+
+    // Split the play context into the clock and the down and distance
+    let play_context = format!("{}", PlayContext::from(&context));
+    let (clock, situation) = match play_context.trim_start_matches('[').split_once("] ") {
+        Some((clock, down_dist)) => (String::from(clock), String::from(down_dist)),
+        None => (String::new(), play_context.clone())
+    };
+    let (offense, defense) = if context.home_possession() {
+        (context.home_team_short(), context.away_team_short())
+    } else {
+        (context.away_team_short(), context.home_team_short())
+    };
+    let next_play = if context.game_over() {
+        "Game over"
+    } else if context.next_play_kickoff() {
+        "Kickoff"
+    } else if context.next_play_extra_point() {
+        "Extra point"
+    } else {
+        situation.as_str()
+    };
+
+    // Display the context as a table of fields
+    let lines = [
+        format!(
+            "Score\t{} {} - {} {}",
+            context.home_team_short(), context.home_score(),
+            context.away_team_short(), context.away_score()
+        ),
+        format!("Clock\t{}", clock),
+        format!("Possession\t{} (defense: {})", offense, defense),
+        format!("Next play\t{}", next_play),
+        format!(
+            "Timeouts\t{} {}, {} {}",
+            context.home_team_short(), context.home_timeouts(),
+            context.away_team_short(), context.away_timeouts()
+        ),
+        format!("Neutral site\t{}", context.neutral_site())
+    ];
+    println!("{}", tabulate(&lines.join("\n")));
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_yard_line() {
+        assert_eq!(parse_yard_line("OWN 25"), Ok(75));
+        assert_eq!(parse_yard_line("OPP 25"), Ok(25));
+        assert_eq!(parse_yard_line("own 10"), Ok(90));
+        assert_eq!(parse_yard_line("50"), Ok(50));
+        assert_eq!(parse_yard_line("OWN 50"), Ok(50));
+    }
+
+    #[test]
+    fn test_parse_yard_line_invalid() {
+        for yard_line in ["", "25", "OWN", "OWN 51", "OPP -5", "MID 25", "OWN twenty"] {
+            assert!(parse_yard_line(yard_line).is_err(), "{}", yard_line);
+        }
+    }
+
+    #[test]
+    fn test_parse_clock() {
+        assert_eq!(parse_clock("15:00"), Ok(900));
+        assert_eq!(parse_clock("0:07"), Ok(7));
+        assert_eq!(parse_clock("0:00"), Ok(0));
+        assert_eq!(parse_clock("2:30"), Ok(150));
+    }
+
+    #[test]
+    fn test_parse_clock_invalid() {
+        for clock in ["1:60", "15:01", "16:00", "90", "", "a:00", "1:-1"] {
+            assert!(parse_clock(clock).is_err(), "{}", clock);
+        }
+    }
+}
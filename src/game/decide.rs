@@ -0,0 +1,201 @@
+use indicatif::ProgressBar;
+
+use fbsim_core::game::context::GameContext;
+use fbsim_core::game::play::GameSimulator;
+use fbsim_core::game::play::call::{PlayCall, PlayCallSimulator};
+use fbsim_core::game::play::context::PlayContext;
+use fbsim_core::team::FootballTeam;
+
+use crate::benchmark::tabulate;
+use crate::cli::game::FbsimGameDecideArgs;
+use crate::game::coach::{sim_called_play, CoachPlayCall};
+use crate::game::context::load_context;
+use crate::game::winprob::{confidence_z, wilson_interval, WinProbability};
+use crate::rng::new_rng;
+use crate::team::load::load_team;
+
+/// The 4th down options compared by the decision analyzer
+const OPTIONS: [&str; 4] = ["Go for it (run)", "Go for it (pass)", "Punt", "Field goal"];
+
+/// The same options as named on an earlier down at the end of a half
+const END_OF_HALF_OPTIONS: [&str; 4] = ["Run", "Pass", "Punt", "Field goal"];
+
+/// The most seconds left in the 2nd or 4th quarter for an earlier down to be
+/// decided as an end-of-half situation
+const END_OF_HALF_SECONDS: u32 = 120;
+
+/// Whether a context is an earlier down in the last two minutes of a half
+fn end_of_half_situation(context: &GameContext) -> bool {
+    (context.quarter() == 2 || context.quarter() == 4) &&
+        context.half_seconds() <= END_OF_HALF_SECONDS
+}
+
+/// The play call forced for each 4th down option
+fn option_play_call(option: usize) -> CoachPlayCall {
+    match option {
+        0 => CoachPlayCall::Run,
+        1 => CoachPlayCall::Pass,
+        2 => CoachPlayCall::Punt,
+        _ => CoachPlayCall::FieldGoal
+    }
+}
+
+/// The 4th down option of a play call made by the offense's coach
+fn play_call_option(call: PlayCall) -> Option<usize> {
+    match call {
+        PlayCall::Run => Some(0),
+        PlayCall::Pass => Some(1),
+        PlayCall::Punt => Some(2),
+        PlayCall::FieldGoal => Some(3),
+        _ => None
+    }
+}
+
+pub fn game_decide(args: FbsimGameDecideArgs, seed: Option<u64>) -> Result<(), String> {
+    // Load the home and away teams from their files
+    let home_team: FootballTeam = match load_team(&args.home) {
+        Ok(team) => team,
+        Err(e) => return Err(format!("Error loading home team: {}", e)),
+    };
+    let away_team: FootballTeam = match load_team(&args.away) {
+        Ok(team) => team,
+        Err(e) => return Err(format!("Error loading away team: {}", e)),
+    };
+
+    // Load the context and ensure it is a 4th down or end-of-half play from
+    // scrimmage
+    let context = load_context(&args.context)?;
+    if context.game_over() || context.next_play_kickoff() || context.next_play_extra_point() ||
+        !(context.down() == 4 || end_of_half_situation(&context)) {
+        return Err(format!(
+            "Game context is not a 4th down or end-of-half situation: {}",
+            PlayContext::from(&context)
+        ));
+    }
+    let options = if context.down() == 4 { OPTIONS } else { END_OF_HALF_OPTIONS };
+    let home_offense = context.home_possession();
+    let (offense, defense) = if home_offense {
+        (&home_team, &away_team)
+    } else {
+        (&away_team, &home_team)
+    };
+
+    // Load the number of trials and confidence level
+    let trials: usize = args.trials.unwrap_or(2000);
+    if trials == 0 {
+        return Err(String::from("Number of trials must be greater than 0"));
+    }
+    let confidence: f64 = args.confidence.unwrap_or(0.95);
+    let z = confidence_z(confidence)?;
+
+    // Sample the offense coach's own play calls in this situation
+    let mut rng = new_rng(seed)?;
+    let call_sim = PlayCallSimulator::new();
+    let mut coach_calls: [usize; 4] = [0; 4];
+    for _ in 0..trials {
+        if let Some(option) = play_call_option(call_sim.sim(offense, &context, &mut rng)) {
+            coach_calls[option] += 1;
+        }
+    }
+
+    // Force each option and simulate the remainder of the game many times
+    let game_sim = GameSimulator::new();
+    let progress_bar = ProgressBar::new((OPTIONS.len() * trials) as u64);
+    let mut results: Vec<WinProbability> = Vec::new();
+    for option in 0..OPTIONS.len() {
+        let mut win_prob = WinProbability::default();
+        for _ in 0..trials {
+            let (_play, next_context) = sim_called_play(
+                option_play_call(option), offense, defense, context.clone(), &mut rng
+            );
+            let final_context = if next_context.game_over() {
+                next_context
+            } else {
+                match game_sim.sim(&home_team, &away_team, next_context, &mut rng) {
+                    Ok((_game, c)) => c,
+                    Err(e) => return Err(format!("Error simulating game: {}", e))
+                }
+            };
+            win_prob.record(&final_context);
+            progress_bar.inc(1);
+        }
+        results.push(win_prob);
+    }
+    progress_bar.finish();
+
+    // Compute the offense's win probability and expected margin for each option
+    let outcomes: Vec<(usize, f64)> = results.iter()
+        .map(|r| {
+            if home_offense {
+                (r.home_wins(), r.mean_home_margin())
+            } else {
+                (r.away_wins(), -r.mean_home_margin())
+            }
+        })
+        .collect();
+
+    // Display each option's outcomes and how often the coach calls it
+    let mut option_lines = format!(
+        "Option\tWin Probability\t{}% CI\tTie\tExpected Margin\tCoach Call",
+        confidence * 100_f64
+    );
+    for (option, (result, (wins, margin))) in results.iter().zip(outcomes.iter()).enumerate() {
+        let (low, high) = wilson_interval(*wins, trials, z);
+        let option_line = format!(
+            "{}\t{:.2}%\t[{:.2}%, {:.2}%]\t{:.2}%\t{:+.2}\t{:.2}%",
+            options[option], *wins as f64 * 100_f64 / trials as f64,
+            low * 100_f64, high * 100_f64,
+            result.ties() as f64 * 100_f64 / trials as f64, margin,
+            coach_calls[option] as f64 * 100_f64 / trials as f64
+        );
+        option_lines = option_lines + "\n" + &option_line;
+    }
+    println!();
+    println!("{} @ {} ({} trials per option)", away_team.name(), home_team.name(), trials);
+    println!(
+        "{} {} - {} {}, {} has the ball, {}",
+        context.home_team_short(), context.home_score(),
+        context.away_team_short(), context.away_score(),
+        offense.short_name(), PlayContext::from(&context)
+    );
+    println!();
+    println!("Decision outcomes:");
+    println!("{}", tabulate(&option_lines));
+
+    // Recommend the option with the highest win probability, breaking
+    // ties by the expected margin
+    let best = (0..OPTIONS.len())
+        .max_by(|a, b| {
+            outcomes[*a].0.cmp(&outcomes[*b].0)
+                .then(outcomes[*a].1.total_cmp(&outcomes[*b].1))
+        })
+        .unwrap();
+    let coach_best = (0..OPTIONS.len()).max_by_key(|o| coach_calls[*o]).unwrap();
+    println!();
+    println!("Recommendation: {}", options[best]);
+    println!("Most common coach call: {}", options[coach_best]);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use fbsim_core::game::context::GameContextBuilder;
+
+    #[test]
+    fn test_end_of_half_situation() {
+        let context = |quarter: u32, half_seconds: u32| GameContextBuilder::new()
+            .quarter(quarter)
+            .half_seconds(half_seconds)
+            .down(2)
+            .next_play_kickoff(false)
+            .build()
+            .unwrap();
+        assert!(end_of_half_situation(&context(2, 120)));
+        assert!(end_of_half_situation(&context(4, 5)));
+        assert!(!end_of_half_situation(&context(2, 121)));
+        assert!(!end_of_half_situation(&context(3, 900)));
+        assert!(!end_of_half_situation(&context(1, 1000)));
+    }
+}
@@ -1 +1,2 @@
+pub mod benchmark;
 pub mod sim;
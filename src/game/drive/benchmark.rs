@@ -0,0 +1,303 @@
+use std::collections::BTreeMap;
+
+use crate::benchmark::{run_grid, tabulate, write_benchmark_output, BenchmarkDistribution, BenchmarkReport, SkillGrid};
+use crate::cli::game::drive::FbsimGameDriveBenchmarkArgs;
+use crate::rng::new_rng;
+
+use fbsim_core::game::context::{GameContext, GameContextBuilder};
+use fbsim_core::game::play::{DriveResult, DriveSimulator};
+use fbsim_core::game::play::result::PlayTypeResult;
+use fbsim_core::team::FootballTeam;
+
+use indicatif::ProgressBar;
+use statrs::statistics::Statistics;
+
+/// The starting yard lines benchmarked, measured from the offense's own goal line
+const START_YARD_LINES: [u32; 9] = [10, 20, 30, 40, 50, 60, 70, 80, 90];
+
+/// Per-drive outcome samples for one group of drives
+#[derive(Default)]
+struct DriveSamples {
+    touchdowns: Vec<f64>,
+    field_goals: Vec<f64>,
+    punts: Vec<f64>,
+    turnovers: Vec<f64>,
+    plays: Vec<f64>,
+    yards: Vec<f64>,
+    durations: Vec<f64>
+}
+
+impl DriveSamples {
+    /// The names of the tracked metrics, in the order returned by `metrics`
+    const METRICS: [&'static str; 7] = [
+        "touchdown_rate",
+        "field_goal_rate",
+        "punt_rate",
+        "turnover_rate",
+        "plays",
+        "yards",
+        "duration"
+    ];
+
+    /// Borrow the samples of each tracked metric
+    fn metrics(&self) -> [&Vec<f64>; 7] {
+        [
+            &self.touchdowns,
+            &self.field_goals,
+            &self.punts,
+            &self.turnovers,
+            &self.plays,
+            &self.yards,
+            &self.durations
+        ]
+    }
+
+    /// Record the outcome of a drive
+    fn record(&mut self, result: DriveResult, plays: usize, yards: i32, duration: u32) {
+        let turnover = matches!(
+            result,
+            DriveResult::Interception | DriveResult::PickSix |
+            DriveResult::Fumble | DriveResult::ScoopAndScore
+        );
+        self.touchdowns.push(if result == DriveResult::Touchdown { 1.0 } else { 0.0 });
+        self.field_goals.push(if result == DriveResult::FieldGoal { 1.0 } else { 0.0 });
+        self.punts.push(if result == DriveResult::Punt { 1.0 } else { 0.0 });
+        self.turnovers.push(if turnover { 1.0 } else { 0.0 });
+        self.plays.push(plays as f64);
+        self.yards.push(yards as f64);
+        self.durations.push(duration as f64);
+    }
+
+    /// Merge the samples of another group of drives
+    fn merge(&mut self, other: DriveSamples) {
+        self.touchdowns.extend(other.touchdowns);
+        self.field_goals.extend(other.field_goals);
+        self.punts.extend(other.punts);
+        self.turnovers.extend(other.turnovers);
+        self.plays.extend(other.plays);
+        self.yards.extend(other.yards);
+        self.durations.extend(other.durations);
+    }
+}
+
+/// Drive outcome accumulators, grouped by skill differential, starting yard
+/// line and grid cell
+pub struct DriveBenchmark {
+    iterations: usize,
+    by_skill_diff: BTreeMap<i32, DriveSamples>,
+    by_yard_line: BTreeMap<u32, DriveSamples>,
+    by_cell: BTreeMap<(u32, u32, u32), DriveSamples>
+}
+
+impl DriveBenchmark {
+    /// Initialize empty accumulators for the given grid and iterations per cell
+    fn new(grid: &SkillGrid, iterations: usize) -> DriveBenchmark {
+        let mut benchmark = DriveBenchmark{
+            iterations,
+            by_skill_diff: BTreeMap::new(),
+            by_yard_line: BTreeMap::new(),
+            by_cell: BTreeMap::new()
+        };
+        for (i, j) in grid.cells() {
+            let skill_diff = grid.offense(i) as i32 - grid.defense(j) as i32;
+            benchmark.by_skill_diff.insert(skill_diff, DriveSamples::default());
+            for yard_line in START_YARD_LINES {
+                benchmark.by_cell.insert((grid.offense(i), grid.defense(j), yard_line), DriveSamples::default());
+            }
+        }
+        for yard_line in START_YARD_LINES {
+            benchmark.by_yard_line.insert(yard_line, DriveSamples::default());
+        }
+        benchmark
+    }
+
+    /// Merge the accumulators of another benchmark
+    fn merge(&mut self, other: DriveBenchmark) {
+        for (diff, samples) in other.by_skill_diff.into_iter() {
+            self.by_skill_diff.entry(diff).or_default().merge(samples);
+        }
+        for (yard_line, samples) in other.by_yard_line.into_iter() {
+            self.by_yard_line.entry(yard_line).or_default().merge(samples);
+        }
+        for (cell, samples) in other.by_cell.into_iter() {
+            self.by_cell.entry(cell).or_default().merge(samples);
+        }
+    }
+
+    /// Format the drive efficiency tables
+    fn tables(&self) -> String {
+        let header = "Touchdown\tField Goal\tPunt\tTurnover\tMean Plays\tMean Yards\tMean Time (s)";
+        let mut out = String::new();
+
+        // Format drive efficiency by skill diff
+        let mut skill_lines = format!("Skill Diff\t{}", header);
+        for (diff, samples) in self.by_skill_diff.iter() {
+            skill_lines = skill_lines + "\n" + &format!("{}\t{}", diff, efficiency_line(samples));
+        }
+        out.push_str("\nDrive efficiency (skill):\n");
+        out.push_str(&tabulate(&skill_lines));
+        out.push('\n');
+
+        // Format drive efficiency by starting yard line
+        let mut yard_line_lines = format!("Yard Line\t{}", header);
+        for (yard_line, samples) in self.by_yard_line.iter() {
+            yard_line_lines = yard_line_lines + "\n" + &format!("{}\t{}", yard_line, efficiency_line(samples));
+        }
+        out.push_str("\nDrive efficiency (starting yard line):\n");
+        out.push_str(&tabulate(&yard_line_lines));
+        out.push('\n');
+
+        // Format drive efficiency by grid cell and starting yard line
+        let mut cell_lines = format!("Offense\tDefense\tYard Line\t{}", header);
+        for ((offense, defense, yard_line), samples) in self.by_cell.iter() {
+            cell_lines = cell_lines + "\n" + &format!(
+                "{}\t{}\t{}\t{}", offense, defense, yard_line, efficiency_line(samples)
+            );
+        }
+        out.push_str("\nDrive efficiency (offense, defense, starting yard line):\n");
+        out.push_str(&tabulate(&cell_lines));
+        out.push('\n');
+        out
+    }
+
+    /// Summarize the benchmark as a machine-readable report
+    pub fn report(&self, grid: &SkillGrid) -> BenchmarkReport {
+        let mut distributions: Vec<BenchmarkDistribution> = Vec::new();
+        for m in 0..DriveSamples::METRICS.len() {
+            let by_skill_diff: BTreeMap<i32, Vec<f64>> = self.by_skill_diff.iter()
+                .map(|(diff, samples)| (*diff, samples.metrics()[m].clone()))
+                .collect();
+            distributions.push(BenchmarkDistribution::from_samples(DriveSamples::METRICS[m], "skill_diff", &by_skill_diff));
+        }
+        for m in 0..DriveSamples::METRICS.len() {
+            let by_yard_line: BTreeMap<u32, Vec<f64>> = self.by_yard_line.iter()
+                .map(|(yard_line, samples)| (*yard_line, samples.metrics()[m].clone()))
+                .collect();
+            distributions.push(BenchmarkDistribution::from_samples(DriveSamples::METRICS[m], "yard_line", &by_yard_line));
+        }
+        for (i, j) in grid.cells() {
+            let (offense, defense) = (grid.offense(i), grid.defense(j));
+            for m in 0..DriveSamples::METRICS.len() {
+                let by_yard_line: BTreeMap<u32, Vec<f64>> = START_YARD_LINES.iter()
+                    .filter_map(|yard_line| self.by_cell.get(&(offense, defense, *yard_line))
+                        .map(|samples| (*yard_line, samples.metrics()[m].clone())))
+                    .collect();
+                let name = format!("{}[offense={},defense={}]", DriveSamples::METRICS[m], offense, defense);
+                distributions.push(BenchmarkDistribution::from_samples(&name, "yard_line", &by_yard_line));
+            }
+        }
+        BenchmarkReport{
+            benchmark: String::from("drive"),
+            iterations: self.iterations,
+            skill_step: grid.step(),
+            matrices: Vec::new(),
+            distributions
+        }
+    }
+}
+
+/// Format the outcome rates and per-drive means of a group of drives
+fn efficiency_line(samples: &DriveSamples) -> String {
+    format!(
+        "{:.2}%\t{:.2}%\t{:.2}%\t{:.2}%\t{:.4}\t{:.4}\t{:.4}",
+        samples.touchdowns.clone().mean() * 100_f64,
+        samples.field_goals.clone().mean() * 100_f64,
+        samples.punts.clone().mean() * 100_f64,
+        samples.turnovers.clone().mean() * 100_f64,
+        samples.plays.clone().mean(),
+        samples.yards.clone().mean(),
+        samples.durations.clone().mean()
+    )
+}
+
+/// Simulate drives across a skill grid and starting yard lines and track their outcomes
+pub fn sim_drive_benchmark(grid: &SkillGrid, iterations: usize, threads: usize, seed: Option<u64>) -> Result<DriveBenchmark, String> {
+    if iterations == 0 {
+        return Err(String::from("Number of iterations must be greater than 0"));
+    }
+
+    // Instantiate the RNG and a progress bar for benchmark progress
+    let mut rng = new_rng(seed)?;
+    let progress_bar = ProgressBar::new((grid.len() * grid.len() * START_YARD_LINES.len() * iterations) as u64);
+
+    // Run many drive simulations on each thread and track the observed
+    // drive outcomes by skill differential, yard line and grid cell
+    let results = run_grid(grid.cells(), threads, &mut rng, |cells, rng| {
+        let drive_sim = DriveSimulator::new();
+        let mut benchmark = DriveBenchmark::new(grid, iterations);
+        for &(i, j) in cells {
+            // Create the home and away teams, the home team's offense has
+            // the ball against the away team's defense
+            let offense = grid.offense(i);
+            let defense = grid.defense(j);
+            let skill_diff = offense as i32 - defense as i32;
+            let home_team = FootballTeam::from_overalls(
+                "Home Team",
+                "HOME",
+                offense,
+                grid.defense(i)
+            ).unwrap();
+            let away_team = FootballTeam::from_overalls(
+                "Away Team",
+                "AWAY",
+                grid.offense(j),
+                defense
+            ).unwrap();
+            for yard_line in START_YARD_LINES {
+                for _ in 0..iterations {
+                    // Simulate a drive starting at 1st & 10 from the yard line
+                    let context: GameContext = GameContextBuilder::new()
+                        .down(1)
+                        .distance(10)
+                        .yard_line(yard_line)
+                        .next_play_kickoff(false)
+                        .build()
+                        .unwrap();
+                    let (drive, new_context) = drive_sim.sim(&home_team, &away_team, context.clone(), rng);
+
+                    // Track the drive outcome, excluding any extra point
+                    let plays = drive.plays().iter()
+                        .filter(|p| !matches!(p.result(), PlayTypeResult::ExtraPoint(_)))
+                        .count();
+                    let duration = context.half_seconds().saturating_sub(new_context.half_seconds());
+                    let result = *drive.result();
+                    let yards = drive.total_yards();
+                    benchmark.by_skill_diff.entry(skill_diff).or_default()
+                        .record(result, plays, yards, duration);
+                    benchmark.by_yard_line.entry(yard_line).or_default()
+                        .record(result, plays, yards, duration);
+                    benchmark.by_cell.entry((offense, defense, yard_line)).or_default()
+                        .record(result, plays, yards, duration);
+
+                    // Increment the progress bar
+                    progress_bar.inc(1);
+                }
+            }
+        }
+        Ok(benchmark)
+    })?;
+    progress_bar.finish();
+
+    // Merge the results of each thread
+    let mut benchmark = DriveBenchmark::new(grid, iterations);
+    for result in results.into_iter() {
+        benchmark.merge(result);
+    }
+    Ok(benchmark)
+}
+
+pub fn drive_benchmark(args: FbsimGameDriveBenchmarkArgs, seed: Option<u64>) -> Result<(), String> {
+    // Load the benchmark arguments and run the benchmark
+    let iterations: usize = args.iterations.unwrap_or(1000);
+    let grid = SkillGrid::new(args.skill_step.unwrap_or(10))?;
+    let threads: usize = args.threads.unwrap_or(1);
+    let benchmark = sim_drive_benchmark(&grid, iterations, threads, seed)?;
+
+    // Write the benchmark results in the given output format
+    write_benchmark_output(
+        &args.output_format,
+        &args.output_file,
+        || benchmark.tables(),
+        || benchmark.report(&grid)
+    )
+}
@@ -3,35 +3,28 @@ use std::str::FromStr;
 
 use rand::Rng;
 
-use fbsim_core::game::play::DriveSimulator;
+use fbsim_core::game::play::{Drive, DriveSimulator};
 use fbsim_core::game::context::{GameContext, GameContextBuilder};
-use fbsim_core::team::FootballTeam;
 
 use crate::cli::game::drive::FbsimGameDriveSimArgs;
 use crate::cli::output::OutputFormat;
+use crate::game::until::StopCondition;
+use crate::rng::new_rng;
+use crate::team::load::load_game_teams;
 
 use serde_json;
 
-pub fn drive_sim(args: FbsimGameDriveSimArgs) -> Result<(), String> {
-    // Load the home and away teams from their files
-    let home_team_file_res = &fs::read_to_string(&args.home);
-    let home_team_file = match home_team_file_res {
-        Ok(file) => file,
-        Err(e) => return Err(format!("Error loading home team file: {}", e)),
-    };
-    let home_team: FootballTeam = match serde_json::from_str(home_team_file) {
-        Ok(team) => team,
-        Err(e) => return Err(format!("Error loading home team: {}", e)),
-    };
-    let away_team_file_res = &fs::read_to_string(&args.away);
-    let away_team_file = match away_team_file_res {
-        Ok(file) => file,
-        Err(e) => return Err(format!("Error loading away team file: {}", e)),
-    };
-    let away_team: FootballTeam = match serde_json::from_str(away_team_file) {
-        Ok(team) => team,
-        Err(e) => return Err(format!("Error loading away team: {}", e)),
-    };
+pub fn drive_sim(args: FbsimGameDriveSimArgs, seed: Option<u64>, wait: bool) -> Result<(), String> {
+    // Load the output format, which is json or the default
+    let output_format = OutputFormat::from_str(
+        &args.output_format.clone().unwrap_or(String::from(""))
+    ).unwrap();
+    if output_format == OutputFormat::Csv {
+        return Err(String::from("CSV output is only supported by the benchmark commands"));
+    }
+
+    // Load the home and away teams from their files or the league
+    let (home_team, away_team) = load_game_teams(&args.teams, wait)?;
 
     // Decide whether to update the context
     let is_context_given: bool = args.context.is_some();
@@ -47,7 +40,7 @@ pub fn drive_sim(args: FbsimGameDriveSimArgs) -> Result<(), String> {
     };
 
     // Load the context from its file or initialize
-    let mut rng = rand::thread_rng();
+    let mut rng = new_rng(seed)?;
     let context: GameContext = if is_context_given {
         match &args.context {
             Some(x) => {
@@ -80,25 +73,51 @@ pub fn drive_sim(args: FbsimGameDriveSimArgs) -> Result<(), String> {
         return Err(String::from("Cannot simulate drive, game is already over"));
     }
 
-    // Instantiate the simulator and simulate the drive
+    // Load the stop condition and the most drives to simulate
+    let until: Option<StopCondition> = match &args.until {
+        Some(x) => Some(StopCondition::from_str(x)?),
+        None => None
+    };
+    if args.count == Some(0) {
+        return Err(String::from("Number of drives must be greater than 0"));
+    }
+    let multiple: bool = until.is_some() || args.count.is_some();
+    let count: usize = args.count.unwrap_or(if until.is_some() { usize::MAX } else { 1 });
+
+    // Instantiate the simulator and simulate until a stop condition is reached
     let drive_sim = DriveSimulator::new();
-    let (drive, new_context) = drive_sim.sim(
-        &home_team,
-        &away_team,
-        context,
-        &mut rng
-    );
+    let mut drives: Vec<Drive> = Vec::new();
+    let mut new_context = context;
+    while drives.len() < count {
+        let (drive, next_context) = drive_sim.sim(
+            &home_team,
+            &away_team,
+            new_context.clone(),
+            &mut rng
+        );
+        let stop = next_context.game_over() ||
+            until.as_ref().is_some_and(|u| u.reached(&new_context, &next_context));
+        drives.push(drive);
+        new_context = next_context;
+        if stop {
+            break;
+        }
+    }
 
-    // Serialize the drive result as a string based on the given output format
-    let output_format = OutputFormat::from_str(
-        &args.output_format.clone().unwrap_or(String::from(""))
-    ).unwrap();
+    // Serialize the drive results as a string based on the given output format
     let drive_str: String = match output_format {
         OutputFormat::Json => {
-            serde_json::to_string_pretty(&drive).unwrap()
+            if multiple {
+                serde_json::to_string_pretty(&drives).unwrap()
+            } else {
+                serde_json::to_string_pretty(&drives[0]).unwrap()
+            }
         },
-        OutputFormat::Default => {
-            format!("{}", drive)
+        OutputFormat::Csv | OutputFormat::Default => {
+            drives.iter()
+                .map(|x| format!("{}", x))
+                .collect::<Vec<String>>()
+                .join("\n\n")
         }
     };
 
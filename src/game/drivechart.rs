@@ -0,0 +1,112 @@
+use fbsim_core::game::play::{Drive, DriveResult, Game, Play};
+use fbsim_core::game::play::context::PlayContext;
+use fbsim_core::game::play::result::PlayResult;
+
+use crate::benchmark::tabulate;
+use crate::game::boxscore::{elapsed_seconds, scrimmage_play};
+
+/// The width of the field in the drive chart, in characters
+const FIELD_WIDTH: u32 = 50;
+
+/// The yard line of a play's offense, measured from its own goal line
+fn offense_yard_line(play: &Play) -> u32 {
+    PlayContext::from(play.context()).yard_line()
+}
+
+/// Format a yard line measured from the offense's own goal line
+fn format_yard_line(yard_line: u32) -> String {
+    match yard_line {
+        50 => String::from("50"),
+        y if y >= 100 => String::from("End zone"),
+        y if y < 50 => format!("OWN {}", y),
+        y => format!("OPP {}", 100_u32.saturating_sub(y))
+    }
+}
+
+/// A short label for the result of a drive
+fn result_label(result: &DriveResult) -> &'static str {
+    match result {
+        DriveResult::None => "In progress",
+        DriveResult::Punt => "Punt",
+        DriveResult::FieldGoal => "FG",
+        DriveResult::FieldGoalMissed => "Missed FG",
+        DriveResult::Touchdown => "TD",
+        DriveResult::Safety => "Safety",
+        DriveResult::Interception | DriveResult::Fumble => "Turnover",
+        DriveResult::PickSix | DriveResult::ScoopAndScore => "Turnover (TD)",
+        DriveResult::Downs => "Downs",
+        DriveResult::EndOfHalf => "End of half"
+    }
+}
+
+/// The yard line a drive ended at, measured from the offense's own goal line
+fn end_yard_line(result: &DriveResult, last_play: &Play) -> u32 {
+    let line_of_scrimmage = offense_yard_line(last_play);
+    match result {
+        DriveResult::Touchdown => 100,
+        DriveResult::Safety => 0,
+        DriveResult::Punt | DriveResult::FieldGoal | DriveResult::FieldGoalMissed |
+        DriveResult::Interception | DriveResult::PickSix |
+        DriveResult::Fumble | DriveResult::ScoopAndScore => line_of_scrimmage,
+        _ => (line_of_scrimmage as i32 + last_play.result().net_yards()).clamp(0, 100) as u32
+    }
+}
+
+/// Render a drive as a bar over the field, from the offense's own goal line
+/// on the left to the opponent's on the right
+fn drive_bar(start: u32, end: u32) -> String {
+    let column = |yard_line: u32| (yard_line * FIELD_WIDTH / 100).min(FIELD_WIDTH - 1) as usize;
+    let mut field: Vec<char> = vec!['.'; FIELD_WIDTH as usize];
+    field[column(50)] = ':';
+    let (low, high) = (column(start.min(end)), column(start.max(end)));
+    for c in field.iter_mut().take(high + 1).skip(low) {
+        *c = '=';
+    }
+    if end >= start {
+        field[high] = '>';
+    } else {
+        field[low] = '<';
+    }
+    format!("|{}|", field.into_iter().collect::<String>())
+}
+
+/// Format a drive as a line of the drive chart, or None if it has no plays
+/// from scrimmage
+fn drive_line(number: usize, drive: &Drive) -> Option<String> {
+    let plays: Vec<&Play> = drive.plays().iter().filter(|p| scrimmage_play(p)).collect();
+    let first_play = plays.first()?;
+    let last_play = plays.last()?;
+    let context = first_play.context();
+    let offense = if context.home_possession() {
+        context.home_team_short()
+    } else {
+        context.away_team_short()
+    };
+    let start = offense_yard_line(first_play);
+    let end = end_yard_line(drive.result(), last_play);
+    let duration: u32 = plays.iter().map(|p| elapsed_seconds(p)).sum();
+    let quarter = if context.quarter() <= 4 {
+        format!("{}Q", context.quarter())
+    } else {
+        format!("{}OT", context.quarter() - 4)
+    };
+    Some(
+        format!(
+            "{}\t{}\t{}\t{}\t{}\t{}\t{}\t{}\t{}:{:02}\t{}",
+            number, offense, quarter, format_yard_line(start), format_yard_line(end),
+            result_label(drive.result()), plays.len(), drive.total_yards(),
+            duration / 60, duration % 60, drive_bar(start, end)
+        )
+    )
+}
+
+/// Render the drives of a game as a chart over a 100-yard field
+pub fn drive_chart(game: &Game) -> String {
+    let mut lines = String::from("Drive\tTeam\tQtr\tStart\tEnd\tResult\tPlays\tYards\tTOP\tField");
+    for (i, drive) in game.drives().iter().enumerate() {
+        if let Some(line) = drive_line(i + 1, drive) {
+            lines = lines + "\n" + &line;
+        }
+    }
+    tabulate(&lines)
+}
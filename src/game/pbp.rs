@@ -0,0 +1,144 @@
+use std::fs;
+
+use fbsim_core::game::context::GameContext;
+use fbsim_core::game::play::{Game, Play};
+use fbsim_core::game::play::context::PlayContext;
+use fbsim_core::game::play::result::{PlayResult, PlayTypeResult};
+
+use serde::Serialize;
+use serde_json;
+
+use crate::game::context::quarter_seconds;
+
+/// The columns of a play-by-play CSV export
+const PBP_CSV_HEADER: &str = "drive,play,quarter,clock,down,distance,yard_line,offense,play_type,net_yards,pass_distance,complete,yards_after_catch,duration,home_score,away_score";
+
+/// A single play of a game, flattened into one row
+#[derive(Serialize)]
+pub struct PlayRow {
+    drive: usize,
+    play: usize,
+    quarter: u32,
+    clock: String,
+    down: u32,
+    distance: u32,
+    yard_line: u32,
+    offense: String,
+    play_type: &'static str,
+    net_yards: i32,
+    pass_distance: Option<i32>,
+    complete: Option<bool>,
+    yards_after_catch: Option<i32>,
+    duration: u32,
+    home_score: u32,
+    away_score: u32
+}
+
+/// The name of the type of a play result
+fn play_type(result: &PlayTypeResult) -> &'static str {
+    match result {
+        PlayTypeResult::BetweenPlay(_) => "between_play",
+        PlayTypeResult::Run(_) => "run",
+        PlayTypeResult::Pass(_) => "pass",
+        PlayTypeResult::FieldGoal(_) => "field_goal",
+        PlayTypeResult::Punt(_) => "punt",
+        PlayTypeResult::Kickoff(_) => "kickoff",
+        PlayTypeResult::ExtraPoint(_) => "extra_point",
+        PlayTypeResult::QbKneel(_) => "qb_kneel",
+        PlayTypeResult::QbSpike(_) => "qb_spike"
+    }
+}
+
+/// Format an optional value as a CSV cell, leaving it empty if missing
+fn csv_cell<T: std::fmt::Display>(value: &Option<T>) -> String {
+    match value {
+        Some(v) => v.to_string(),
+        None => String::new()
+    }
+}
+
+impl PlayRow {
+    /// Flatten a play into a row given its drive and play indices
+    pub fn new(drive: usize, play: usize, p: &Play) -> PlayRow {
+        let context: &GameContext = p.context();
+        let result = p.result();
+        let next_context = result.next_context(context);
+        let clock = quarter_seconds(context);
+        let (pass_distance, complete, yards_after_catch) = match result {
+            PlayTypeResult::Pass(r) | PlayTypeResult::QbSpike(r) => (
+                Some(r.pass_dist()), Some(r.complete()), Some(r.yards_after_catch())
+            ),
+            _ => (None, None, None)
+        };
+        PlayRow{
+            drive,
+            play,
+            quarter: context.quarter(),
+            clock: format!("{}:{:02}", clock / 60, clock % 60),
+            down: context.down(),
+            distance: context.distance(),
+            yard_line: PlayContext::from(context).yard_line(),
+            offense: String::from(if context.home_possession() {
+                context.home_team_short()
+            } else {
+                context.away_team_short()
+            }),
+            play_type: play_type(result),
+            net_yards: result.net_yards(),
+            pass_distance,
+            complete,
+            yards_after_catch,
+            duration: result.play_duration(),
+            home_score: next_context.home_score(),
+            away_score: next_context.away_score()
+        }
+    }
+
+    /// Format the row as a line of CSV
+    pub fn to_csv(&self) -> String {
+        format!(
+            "{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{}",
+            self.drive, self.play, self.quarter, self.clock, self.down, self.distance,
+            self.yard_line, self.offense, self.play_type, self.net_yards,
+            csv_cell(&self.pass_distance), csv_cell(&self.complete),
+            csv_cell(&self.yards_after_catch), self.duration,
+            self.home_score, self.away_score
+        )
+    }
+}
+
+/// Flatten every play of a game into rows, in order
+pub fn play_rows(game: &Game) -> Vec<PlayRow> {
+    let mut rows: Vec<PlayRow> = Vec::new();
+    for (i, drive) in game.drives().iter().enumerate() {
+        for (j, play) in drive.plays().iter().enumerate() {
+            rows.push(PlayRow::new(i, j, play));
+        }
+    }
+    rows
+}
+
+/// Export the play-by-play of a game to CSV and/or NDJSON files
+pub fn write_pbp(game: &Game, pbp_csv: &Option<String>, pbp_ndjson: &Option<String>) -> Result<(), String> {
+    let rows = play_rows(game);
+    if let Some(path) = pbp_csv {
+        let mut lines: Vec<String> = vec![String::from(PBP_CSV_HEADER)];
+        lines.extend(rows.iter().map(|row| row.to_csv()));
+        if let Err(e) = fs::write(path, lines.join("\n") + "\n") {
+            return Err(format!("Error writing play-by-play CSV file: {}", e));
+        }
+    }
+    if let Some(path) = pbp_ndjson {
+        let mut ndjson = String::new();
+        for row in rows.iter() {
+            match serde_json::to_string(row) {
+                Ok(s) => ndjson = ndjson + &s + "\n",
+                Err(e) => return Err(format!("Error serializing play-by-play: {}", e))
+            }
+        }
+        if let Err(e) = fs::write(path, ndjson) {
+            return Err(format!("Error writing play-by-play NDJSON file: {}", e));
+        }
+    }
+    Ok(())
+}
@@ -1,7 +1,8 @@
 use std::collections::BTreeMap;
-use std::io::{Write, stdout};
 
+use crate::benchmark::{merge_samples, run_grid, tabulate, write_benchmark_output, BenchmarkDistribution, BenchmarkReport, SkillGrid};
 use crate::cli::game::play::FbsimGamePlayBenchmarkArgs;
+use crate::rng::new_rng;
 
 use fbsim_core::game::context::GameContext;
 use fbsim_core::game::play::PlaySimulator;
@@ -9,113 +10,484 @@ use fbsim_core::game::play::result::{PlayResult, PlayTypeResult};
 use fbsim_core::team::FootballTeam;
 
 use indicatif::ProgressBar;
-use tabwriter::TabWriter;
 use statrs::statistics::Statistics;
 
-pub fn play_benchmark(_args: FbsimGamePlayBenchmarkArgs) -> Result<(), String> {
-    // Instantiate the simulator and RNG
-    let play_sim = PlaySimulator::new();
-    let mut rng = rand::thread_rng();
-
-    // Initialize grouped skill differential stat maps
-    let mut rushes: BTreeMap<i32, Vec<f64>> = BTreeMap::new();
-    let mut passes: BTreeMap<i32, Vec<f64>> = BTreeMap::new();
-    let mut pass_distances: BTreeMap<i32, Vec<f64>> = BTreeMap::new();
-    let mut yac: BTreeMap<i32, Vec<f64>> = BTreeMap::new();
-    let mut completions: BTreeMap<i32, Vec<f64>> = BTreeMap::new();
-    let mut interceptions: BTreeMap<i32, Vec<f64>> = BTreeMap::new();
-    let mut fg_blocks: BTreeMap<i32, Vec<f64>> = BTreeMap::new();
-    let mut fg_blocks_yl: BTreeMap<u32, Vec<f64>> = BTreeMap::new();
-    let mut fg_made: BTreeMap<i32, Vec<f64>> = BTreeMap::new();
-    let mut fg_made_yl: BTreeMap<u32, Vec<f64>> = BTreeMap::new();
-    let mut punt_distance: BTreeMap<i32, Vec<f64>> = BTreeMap::new();
-    let mut punt_distance_yl: BTreeMap<u32, Vec<f64>> = BTreeMap::new();
-    let mut punt_return_yards: BTreeMap<i32, Vec<f64>> = BTreeMap::new();
-    let mut punt_return_yards_yl: BTreeMap<u32, Vec<f64>> = BTreeMap::new();
-    let mut kickoff_distance: BTreeMap<i32, Vec<f64>> = BTreeMap::new();
-    let mut kick_return_yards: BTreeMap<i32, Vec<f64>> = BTreeMap::new();
-    let mut play_durations: Vec<f64> = Vec::new();
-    let mut between_play_durations: Vec<f64> = Vec::new();
-    for i in 0..11 {
-        let off = ((10 - i) * 10) as i32;
-        let def = (i * 10) as i32;
-        rushes.insert(off - def, Vec::new());
-        passes.insert(off - def, Vec::new());
-        pass_distances.insert(off - def, Vec::new());
-        yac.insert(off - def, Vec::new());
-        completions.insert(off - def, Vec::new());
-        interceptions.insert(off - def, Vec::new());
-        fg_blocks.insert(off - def, Vec::new());
-        fg_made.insert(off - def, Vec::new());
-        punt_distance.insert(off - def, Vec::new());
-        punt_return_yards.insert(off - def, Vec::new());
-        kickoff_distance.insert(off - def, Vec::new());
-        kick_return_yards.insert(off - def, Vec::new());
-        fg_blocks_yl.insert(i * 10, Vec::new());
-        fg_made_yl.insert(i * 10, Vec::new());
-        punt_distance_yl.insert(i * 10, Vec::new());
-        punt_return_yards_yl.insert(i * 10, Vec::new());
+/// Per-play statistic accumulators, grouped by skill differential or yard line
+pub struct PlayBenchmark {
+    iterations: usize,
+    rushes: BTreeMap<i32, Vec<f64>>,
+    passes: BTreeMap<i32, Vec<f64>>,
+    pass_distances: BTreeMap<i32, Vec<f64>>,
+    yac: BTreeMap<i32, Vec<f64>>,
+    completions: BTreeMap<i32, Vec<f64>>,
+    interceptions: BTreeMap<i32, Vec<f64>>,
+    fg_blocks: BTreeMap<i32, Vec<f64>>,
+    fg_blocks_yl: BTreeMap<u32, Vec<f64>>,
+    fg_made: BTreeMap<i32, Vec<f64>>,
+    fg_made_yl: BTreeMap<u32, Vec<f64>>,
+    punt_distance: BTreeMap<i32, Vec<f64>>,
+    punt_distance_yl: BTreeMap<u32, Vec<f64>>,
+    punt_return_yards: BTreeMap<i32, Vec<f64>>,
+    punt_return_yards_yl: BTreeMap<u32, Vec<f64>>,
+    kickoff_distance: BTreeMap<i32, Vec<f64>>,
+    kick_return_yards: BTreeMap<i32, Vec<f64>>,
+    play_durations: Vec<f64>,
+    between_play_durations: Vec<f64>
+}
+
+impl PlayBenchmark {
+    /// Initialize empty accumulators for the given grid and iterations per cell
+    fn new(grid: &SkillGrid, iterations: usize) -> PlayBenchmark {
+        let mut benchmark = PlayBenchmark{
+            iterations,
+            rushes: BTreeMap::new(),
+            passes: BTreeMap::new(),
+            pass_distances: BTreeMap::new(),
+            yac: BTreeMap::new(),
+            completions: BTreeMap::new(),
+            interceptions: BTreeMap::new(),
+            fg_blocks: BTreeMap::new(),
+            fg_blocks_yl: BTreeMap::new(),
+            fg_made: BTreeMap::new(),
+            fg_made_yl: BTreeMap::new(),
+            punt_distance: BTreeMap::new(),
+            punt_distance_yl: BTreeMap::new(),
+            punt_return_yards: BTreeMap::new(),
+            punt_return_yards_yl: BTreeMap::new(),
+            kickoff_distance: BTreeMap::new(),
+            kick_return_yards: BTreeMap::new(),
+            play_durations: Vec::new(),
+            between_play_durations: Vec::new()
+        };
+        for i in 0..grid.len() {
+            let skill_diff = grid.skill_diff(i);
+            benchmark.rushes.insert(skill_diff, Vec::new());
+            benchmark.passes.insert(skill_diff, Vec::new());
+            benchmark.pass_distances.insert(skill_diff, Vec::new());
+            benchmark.yac.insert(skill_diff, Vec::new());
+            benchmark.completions.insert(skill_diff, Vec::new());
+            benchmark.interceptions.insert(skill_diff, Vec::new());
+            benchmark.fg_blocks.insert(skill_diff, Vec::new());
+            benchmark.fg_made.insert(skill_diff, Vec::new());
+            benchmark.punt_distance.insert(skill_diff, Vec::new());
+            benchmark.punt_return_yards.insert(skill_diff, Vec::new());
+            benchmark.kickoff_distance.insert(skill_diff, Vec::new());
+            benchmark.kick_return_yards.insert(skill_diff, Vec::new());
+        }
+        for i in 0..11 {
+            benchmark.fg_blocks_yl.insert(i * 10, Vec::new());
+            benchmark.fg_made_yl.insert(i * 10, Vec::new());
+            benchmark.punt_distance_yl.insert(i * 10, Vec::new());
+            benchmark.punt_return_yards_yl.insert(i * 10, Vec::new());
+        }
+        benchmark
+    }
+
+    /// Merge the accumulators of another benchmark
+    fn merge(&mut self, other: PlayBenchmark) {
+        merge_samples(&mut self.rushes, other.rushes);
+        merge_samples(&mut self.passes, other.passes);
+        merge_samples(&mut self.pass_distances, other.pass_distances);
+        merge_samples(&mut self.yac, other.yac);
+        merge_samples(&mut self.completions, other.completions);
+        merge_samples(&mut self.interceptions, other.interceptions);
+        merge_samples(&mut self.fg_blocks, other.fg_blocks);
+        merge_samples(&mut self.fg_blocks_yl, other.fg_blocks_yl);
+        merge_samples(&mut self.fg_made, other.fg_made);
+        merge_samples(&mut self.fg_made_yl, other.fg_made_yl);
+        merge_samples(&mut self.punt_distance, other.punt_distance);
+        merge_samples(&mut self.punt_distance_yl, other.punt_distance_yl);
+        merge_samples(&mut self.punt_return_yards, other.punt_return_yards);
+        merge_samples(&mut self.punt_return_yards_yl, other.punt_return_yards_yl);
+        merge_samples(&mut self.kickoff_distance, other.kickoff_distance);
+        merge_samples(&mut self.kick_return_yards, other.kick_return_yards);
+        self.play_durations.extend(other.play_durations);
+        self.between_play_durations.extend(other.between_play_durations);
+    }
+
+    /// Format the play result tables
+    fn tables(&self) -> String {
+        let PlayBenchmark{
+            rushes,
+            passes,
+            pass_distances,
+            yac,
+            completions,
+            interceptions,
+            fg_blocks,
+            fg_blocks_yl,
+            fg_made,
+            fg_made_yl,
+            punt_distance,
+            punt_distance_yl,
+            punt_return_yards,
+            punt_return_yards_yl,
+            kickoff_distance,
+            kick_return_yards,
+            play_durations,
+            between_play_durations,
+            ..
+        } = self;
+        let mut out = String::new();
+
+        // Duration
+        out.push('\n');
+        out.push_str("############\n");
+        out.push_str("# Duration #\n");
+        out.push_str("############\n");
+
+        // Display mean, standard deviation play duration
+        let mut duration_lines = String::from("Mean Duration\tStd Duration");
+        let mean = play_durations.clone().mean();
+        let std = play_durations.clone().std_dev();
+        let duration_line = format!("{:.4}\t{:.4}", mean, std);
+        duration_lines = duration_lines + "\n" + &duration_line;
+        out.push('\n');
+        out.push_str("Play duration distribution:\n");
+        out.push_str(&tabulate(&duration_lines));
+        out.push('\n');
+
+        // Display mean, standard deviation post-play duration
+        let mut duration_lines = String::from("Mean Duration\tStd Duration");
+        let mean = between_play_durations.clone().mean();
+        let std = between_play_durations.clone().std_dev();
+        let duration_line = format!("{:.4}\t{:.4}", mean, std);
+        duration_lines = duration_lines + "\n" + &duration_line;
+        out.push('\n');
+        out.push_str("Post-play duration distribution:\n");
+        out.push_str(&tabulate(&duration_lines));
+        out.push('\n');
+
+        // Rushing
+        out.push('\n');
+        out.push_str("###########\n");
+        out.push_str("# Rushing #\n");
+        out.push_str("###########\n");
+
+        // Display mean, standard deviation rushing
+        let mut rushing_lines = String::from("Skill Diff\tMean Rushing\tStd Rushing");
+        for (diff, rs) in rushes.iter() {
+            let mean = rs.clone().mean();
+            let std = rs.clone().std_dev();
+            let rushing_line = format!("{}\t{:.4}\t{:.4}", diff, mean, std);
+            rushing_lines = rushing_lines + "\n" + &rushing_line;
+        }
+        out.push('\n');
+        out.push_str("Rushing distribution:\n");
+        out.push_str(&tabulate(&rushing_lines));
+        out.push('\n');
+
+        // Field goals
+        out.push('\n');
+        out.push_str("###############\n");
+        out.push_str("# Field goals #\n");
+        out.push_str("###############\n");
+
+        // Display field goal made percentage by skill diff
+        let mut fg_made_lines = String::from("Skill Diff\tField Goal Made Percent");
+        for (diff, fgm) in fg_made.iter() {
+            let mean = fgm.clone().mean();
+            let fg_made_line = format!("{}\t{:.4}", diff, mean);
+            fg_made_lines = fg_made_lines + "\n" + &fg_made_line;
+        }
+        out.push('\n');
+        out.push_str("Field goal made percentages (skill):\n");
+        out.push_str(&tabulate(&fg_made_lines));
+        out.push('\n');
+
+        // Display field goal made percentage by yard line
+        let mut fg_made_yl_lines = String::from("Yard Line\tField Goal Made Percent");
+        for (yl, fgm) in fg_made_yl.iter() {
+            let mean = fgm.clone().mean();
+            let fg_made_yl_line = format!("{}\t{:.4}", yl, mean);
+            fg_made_yl_lines = fg_made_yl_lines + "\n" + &fg_made_yl_line;
+        }
+        out.push('\n');
+        out.push_str("Field goal made percentages (yard line):\n");
+        out.push_str(&tabulate(&fg_made_yl_lines));
+        out.push('\n');
+
+        // Display field goal blocked percentage
+        let mut fg_block_lines = String::from("Skill Diff\tField Goal Block Percent");
+        for (diff, fgb) in fg_blocks.iter() {
+            let mean = fgb.clone().mean();
+            let fg_block_line = format!("{}\t{:.4}", diff, mean);
+            fg_block_lines = fg_block_lines + "\n" + &fg_block_line;
+        }
+        out.push('\n');
+        out.push_str("Field goal block percentages:\n");
+        out.push_str(&tabulate(&fg_block_lines));
+        out.push('\n');
+
+        // Display field goal blocked percentage by yard line
+        let mut fg_blocked_yl_lines = String::from("Yard Line\tField Goal Made Percent");
+        for (yl, fgb) in fg_blocks_yl.iter() {
+            let mean = fgb.clone().mean();
+            let fg_blocked_yl_line = format!("{}\t{:.4}", yl, mean);
+            fg_blocked_yl_lines = fg_blocked_yl_lines + "\n" + &fg_blocked_yl_line;
+        }
+        out.push('\n');
+        out.push_str("Field goal blocked percentages (yard line):\n");
+        out.push_str(&tabulate(&fg_blocked_yl_lines));
+        out.push('\n');
+
+        // Kickoffs
+        out.push('\n');
+        out.push_str("############\n");
+        out.push_str("# Kickoffs #\n");
+        out.push_str("############\n");
+
+        // Display kickoff distance by skill diff
+        let mut kick_dist_lines = String::from("Skill Diff\tMean Kickoff Distance\tStd Kickoff Distance");
+        for (diff, dist) in kickoff_distance.iter() {
+            let mean = dist.clone().mean();
+            let std = dist.clone().std_dev();
+            let kick_dist_line = format!("{}\t{:.4}\t{:.4}", diff, mean, std);
+            kick_dist_lines = kick_dist_lines + "\n" + &kick_dist_line;
+        }
+        out.push('\n');
+        out.push_str("Kickoff distance distribution (skill):\n");
+        out.push_str(&tabulate(&kick_dist_lines));
+        out.push('\n');
+
+        // Display kickoff return yards by skill diff
+        let mut kick_ret_lines = String::from("Skill Diff\tMean Kick Return Yards\tStd Kick Return Yards");
+        for (diff, ret) in kick_return_yards.iter() {
+            let mean = ret.clone().mean();
+            let std = ret.clone().std_dev();
+            let kick_ret_line = format!("{}\t{:.4}\t{:.4}", diff, mean, std);
+            kick_ret_lines = kick_ret_lines + "\n" + &kick_ret_line;
+        }
+        out.push('\n');
+        out.push_str("Kick return yards distribution (skill):\n");
+        out.push_str(&tabulate(&kick_ret_lines));
+        out.push('\n');
+
+        // Punts
+        out.push('\n');
+        out.push_str("#########\n");
+        out.push_str("# Punts #\n");
+        out.push_str("#########\n");
+
+        // Display punt distance by skill diff
+        let mut punt_dist_lines = String::from("Skill Diff\tMean Punt Distance\tStd Punt Distance");
+        for (diff, dist) in punt_distance.iter() {
+            let mean = dist.clone().mean();
+            let std = dist.clone().std_dev();
+            let punt_dist_line = format!("{}\t{:.4}\t{:.4}", diff, mean, std);
+            punt_dist_lines = punt_dist_lines + "\n" + &punt_dist_line;
+        }
+        out.push('\n');
+        out.push_str("Punt distance distribution (skill):\n");
+        out.push_str(&tabulate(&punt_dist_lines));
+        out.push('\n');
+
+        // Display punt distance by yard line
+        let mut punt_dist_yl_lines = String::from("Yard Line\tMean Punt Distance\tStd Punt Distance");
+        for (yl, dist) in punt_distance_yl.iter() {
+            let mean = dist.clone().mean();
+            let std = dist.clone().std_dev();
+            let punt_dist_yl_line = format!("{}\t{:.4}\t{:.4}", yl, mean, std);
+            punt_dist_yl_lines = punt_dist_yl_lines + "\n" + &punt_dist_yl_line;
+        }
+        out.push('\n');
+        out.push_str("Punt distance distribution (yard line):\n");
+        out.push_str(&tabulate(&punt_dist_yl_lines));
+        out.push('\n');
+
+        // Display punt return yards by skill diff
+        let mut punt_ret_lines = String::from("Skill Diff\tMean Punt Return Yards\tStd Punt Return Yards");
+        for (diff, ret) in punt_return_yards.iter() {
+            let mean = ret.clone().mean();
+            let std = ret.clone().std_dev();
+            let punt_ret_line = format!("{}\t{:.4}\t{:.4}", diff, mean, std);
+            punt_ret_lines = punt_ret_lines + "\n" + &punt_ret_line;
+        }
+        out.push('\n');
+        out.push_str("Punt return yards distribution (skill):\n");
+        out.push_str(&tabulate(&punt_ret_lines));
+        out.push('\n');
+
+        // Display punt return yards by yard line
+        let mut punt_ret_yl_lines = String::from("Yard Line\tMean Punt Return Yards\tStd Punt Return Yards");
+        for (yl, ret) in punt_return_yards_yl.iter() {
+            let mean = ret.clone().mean();
+            let std = ret.clone().std_dev();
+            let punt_ret_yl_line = format!("{}\t{:.4}\t{:.4}", yl, mean, std);
+            punt_ret_yl_lines = punt_ret_yl_lines + "\n" + &punt_ret_yl_line;
+        }
+        out.push('\n');
+        out.push_str("Punt return yards distribution (yard line):\n");
+        out.push_str(&tabulate(&punt_ret_yl_lines));
+        out.push('\n');
+
+        // Passing
+        out.push('\n');
+        out.push_str("###########\n");
+        out.push_str("# Passing #\n");
+        out.push_str("###########\n");
+
+        // Display mean, standard deviation passing
+        let mut passing_lines = String::from("Skill Diff\tMean Passing\tStd Passing");
+        for (diff, ps) in passes.iter() {
+            let mean = ps.clone().mean();
+            let std = ps.clone().std_dev();
+            let passing_line = format!("{}\t{:.4}\t{:.4}", diff, mean, std);
+            passing_lines = passing_lines + "\n" + &passing_line;
+        }
+        out.push('\n');
+        out.push_str("Passing distribution:\n");
+        out.push_str(&tabulate(&passing_lines));
+        out.push('\n');
+
+        // Display mean, standard deviation pass distances
+        let mut pass_distance_lines = String::from("Skill Diff\tMean Pass Distance\tStd Pass Distance");
+        for (diff, dists) in pass_distances.iter() {
+            let mean = dists.clone().mean();
+            let std = dists.clone().std_dev();
+            let pass_distance_line = format!("{}\t{:.4}\t{:.4}", diff, mean, std);
+            pass_distance_lines = pass_distance_lines + "\n" + &pass_distance_line;
+        }
+        out.push('\n');
+        out.push_str("Pass distance distribution:\n");
+        out.push_str(&tabulate(&pass_distance_lines));
+        out.push('\n');
+
+        // Display mean, standard deviation yards after catch
+        let mut yac_lines = String::from("Skill Diff\tMean Yards After Catch\tStd Yards After Catch");
+        for (diff, yacs) in yac.iter() {
+            let mean = yacs.clone().mean();
+            let std = yacs.clone().std_dev();
+            let yac_line = format!("{}\t{:.4}\t{:.4}", diff, mean, std);
+            yac_lines = yac_lines + "\n" + &yac_line;
+        }
+        out.push('\n');
+        out.push_str("Yards after catch distribution:\n");
+        out.push_str(&tabulate(&yac_lines));
+        out.push('\n');
+
+        // Display completion percentages
+        let mut comp_lines = String::from("Skill Diff\tCompletion Percentage");
+        for (diff, comps) in completions.iter() {
+            let mean = comps.clone().mean();
+            let comp_line = format!("{}\t{:.4}", diff, mean);
+            comp_lines = comp_lines + "\n" + &comp_line;
+        }
+        out.push('\n');
+        out.push_str("Completion percentages:\n");
+        out.push_str(&tabulate(&comp_lines));
+        out.push('\n');
+
+        // Display interception percentages
+        let mut int_lines = String::from("Skill Diff\tInterception Percentage");
+        for (diff, ints) in interceptions.iter() {
+            let mean = ints.clone().mean();
+            let int_line = format!("{}\t{:.4}", diff, mean);
+            int_lines = int_lines + "\n" + &int_line;
+        }
+        out.push('\n');
+        out.push_str("Interception percentages:\n");
+        out.push_str(&tabulate(&int_lines));
+        out.push('\n');
+        out
+    }
+
+    /// Summarize the benchmark as a machine-readable report
+    pub fn report(&self, grid: &SkillGrid) -> BenchmarkReport {
+        let mut play_durations: BTreeMap<i32, Vec<f64>> = BTreeMap::new();
+        play_durations.insert(0, self.play_durations.clone());
+        let mut between_play_durations: BTreeMap<i32, Vec<f64>> = BTreeMap::new();
+        between_play_durations.insert(0, self.between_play_durations.clone());
+        BenchmarkReport{
+            benchmark: String::from("play"),
+            iterations: self.iterations,
+            skill_step: grid.step(),
+            matrices: Vec::new(),
+            distributions: vec![
+                BenchmarkDistribution::from_samples("play_duration", "all", &play_durations),
+                BenchmarkDistribution::from_samples("between_play_duration", "all", &between_play_durations),
+                BenchmarkDistribution::from_samples("rushes", "skill_diff", &self.rushes),
+                BenchmarkDistribution::from_samples("passes", "skill_diff", &self.passes),
+                BenchmarkDistribution::from_samples("pass_distances", "skill_diff", &self.pass_distances),
+                BenchmarkDistribution::from_samples("yac", "skill_diff", &self.yac),
+                BenchmarkDistribution::from_samples("completions", "skill_diff", &self.completions),
+                BenchmarkDistribution::from_samples("interceptions", "skill_diff", &self.interceptions),
+                BenchmarkDistribution::from_samples("fg_made", "skill_diff", &self.fg_made),
+                BenchmarkDistribution::from_samples("fg_made_yard_line", "yard_line", &self.fg_made_yl),
+                BenchmarkDistribution::from_samples("fg_blocks", "skill_diff", &self.fg_blocks),
+                BenchmarkDistribution::from_samples("fg_blocked_yard_line", "yard_line", &self.fg_blocks_yl),
+                BenchmarkDistribution::from_samples("kickoff_distance", "skill_diff", &self.kickoff_distance),
+                BenchmarkDistribution::from_samples("kick_return_yards", "skill_diff", &self.kick_return_yards),
+                BenchmarkDistribution::from_samples("punt_distance", "skill_diff", &self.punt_distance),
+                BenchmarkDistribution::from_samples("punt_distance_yard_line", "yard_line", &self.punt_distance_yl),
+                BenchmarkDistribution::from_samples("punt_return_yards", "skill_diff", &self.punt_return_yards),
+                BenchmarkDistribution::from_samples("punt_return_yards_yard_line", "yard_line", &self.punt_return_yards_yl)
+            ]
+        }
     }
+}
 
-    // Instantiate a progress bar for benchmark progress
-    let progress_bar = ProgressBar::new(11 * 11 * 100);
+/// Simulate full games play by play across a skill grid and track the play results
+pub fn sim_play_benchmark(grid: &SkillGrid, iterations: usize, threads: usize, seed: Option<u64>) -> Result<PlayBenchmark, String> {
+    if iterations == 0 {
+        return Err(String::from("Number of iterations must be greater than 0"));
+    }
 
-    // Run many game simulations and track the observed
-    // win and tie proportions by skill differential
-    for i in 0..11 {
-        // Set the home offense and away defense
-        let home_off = ((10 - i) * 10) as u32;
-        let away_def = (i * 10) as u32;
-        for j in 0..11 {
-            // Set the away offense and home defense
-            let away_off = ((10 - j) * 10) as u32;
-            let home_def = (j * 10) as u32;
+    // Instantiate the RNG and a progress bar for benchmark progress
+    let mut rng = new_rng(seed)?;
+    let progress_bar = ProgressBar::new((grid.len() * grid.len() * iterations) as u64);
 
+    // Run many game simulations on each thread and track the
+    // observed play results by skill differential and yard line
+    let results = run_grid(grid.cells(), threads, &mut rng, |cells, rng| {
+        let play_sim = PlaySimulator::new();
+        let mut benchmark = PlayBenchmark::new(grid, iterations);
+        for &(i, j) in cells {
             // Calculate the skill diff
-            let skill_diff = away_off as i32 - home_def as i32;
+            let skill_diff = grid.skill_diff(j);
 
             // Create the home and away teams
             let home_team = FootballTeam::from_overalls(
                 "Home Team",
                 "HOME",
-                home_off,
-                home_def
+                grid.offense(i),
+                grid.defense(j)
             ).unwrap();
             let away_team = FootballTeam::from_overalls(
                 "Away Team",
                 "AWAY",
-                away_off,
-                away_def
+                grid.offense(j),
+                grid.defense(i)
             ).unwrap();
-            for _ in 1..101 {
+            for _ in 0..iterations {
                 // Simulate the game
                 let mut context: GameContext = GameContext::new();
                 let mut game_over: bool = false;
                 while !game_over {
                     game_over = context.game_over();
                     if !game_over {
-                        let (play, new_context) = play_sim.sim(&home_team, &away_team, context.clone(), &mut rng);
+                        let (play, new_context) = play_sim.sim(&home_team, &away_team, context.clone(), rng);
                         let play_result = play.result();
                         
                         // Update statistic vecs after each play
-                        play_durations.push(play_result.play_duration() as f64);
+                        benchmark.play_durations.push(play_result.play_duration() as f64);
                         if let PlayTypeResult::BetweenPlay(res) = play.post_play() {
-                            between_play_durations.push(res.duration() as f64);
+                            benchmark.between_play_durations.push(res.duration() as f64);
                         }
                         match play_result {
                             PlayTypeResult::Run(_) => {
-                                let diff_rushes: &mut Vec<f64> = rushes.get_mut(&skill_diff).unwrap();
+                                let diff_rushes: &mut Vec<f64> = benchmark.rushes.get_mut(&skill_diff).unwrap();
                                 diff_rushes.push(play_result.net_yards() as f64);
                             },
                             PlayTypeResult::Pass(res) => {
-                                let diff_passes: &mut Vec<f64> = passes.get_mut(&skill_diff).unwrap();
+                                let diff_passes: &mut Vec<f64> = benchmark.passes.get_mut(&skill_diff).unwrap();
                                 diff_passes.push(play_result.net_yards() as f64);
-                                let diff_distances: &mut Vec<f64> = pass_distances.get_mut(&skill_diff).unwrap();
+                                let diff_distances: &mut Vec<f64> = benchmark.pass_distances.get_mut(&skill_diff).unwrap();
                                 diff_distances.push(res.pass_dist() as f64);
-                                let diff_completions: &mut Vec<f64> = completions.get_mut(&skill_diff).unwrap();
-                                let diff_interceptions: &mut Vec<f64> = interceptions.get_mut(&skill_diff).unwrap();
+                                let diff_completions: &mut Vec<f64> = benchmark.completions.get_mut(&skill_diff).unwrap();
+                                let diff_interceptions: &mut Vec<f64> = benchmark.interceptions.get_mut(&skill_diff).unwrap();
                                 if res.complete() {
-                                    let diff_yac: &mut Vec<f64> = yac.get_mut(&skill_diff).unwrap();
+                                    let diff_yac: &mut Vec<f64> = benchmark.yac.get_mut(&skill_diff).unwrap();
                                     diff_yac.push(res.yards_after_catch() as f64);
                                     diff_completions.push(1.0);
                                 } else if !(res.sack() || res.scramble()) {
@@ -130,10 +502,10 @@ pub fn play_benchmark(_args: FbsimGamePlayBenchmarkArgs) -> Result<(), String> {
                             PlayTypeResult::FieldGoal(res) => {
                                 let yl = play.context().yards_to_touchdown();
                                 let yl_group = ((yl / 10) * 10) as u32;
-                                let diff_fg_blocks: &mut Vec<f64> = fg_blocks.get_mut(&skill_diff).unwrap();
-                                let diff_fg_made: &mut Vec<f64> = fg_made.get_mut(&skill_diff).unwrap();
-                                let yl_fg_blocks: &mut Vec<f64> = fg_blocks_yl.get_mut(&yl_group).unwrap();
-                                let yl_fg_made: &mut Vec<f64> = fg_made_yl.get_mut(&yl_group).unwrap();
+                                let diff_fg_blocks: &mut Vec<f64> = benchmark.fg_blocks.get_mut(&skill_diff).unwrap();
+                                let diff_fg_made: &mut Vec<f64> = benchmark.fg_made.get_mut(&skill_diff).unwrap();
+                                let yl_fg_blocks: &mut Vec<f64> = benchmark.fg_blocks_yl.get_mut(&yl_group).unwrap();
+                                let yl_fg_made: &mut Vec<f64> = benchmark.fg_made_yl.get_mut(&yl_group).unwrap();
                                 if res.made() {
                                     yl_fg_made.push(1.0);
                                     diff_fg_made.push(1.0);
@@ -152,10 +524,10 @@ pub fn play_benchmark(_args: FbsimGamePlayBenchmarkArgs) -> Result<(), String> {
                             PlayTypeResult::Punt(res) => {
                                 let yl = play.context().yards_to_touchdown();
                                 let yl_group = ((yl / 10) * 10) as u32;
-                                let diff_punt_dist: &mut Vec<f64> = punt_distance.get_mut(&skill_diff).unwrap();
-                                let diff_punt_ret: &mut Vec<f64> = punt_return_yards.get_mut(&skill_diff).unwrap();
-                                let yl_punt_dist: &mut Vec<f64> = punt_distance_yl.get_mut(&yl_group).unwrap();
-                                let yl_punt_ret: &mut Vec<f64> = punt_return_yards_yl.get_mut(&yl_group).unwrap();
+                                let diff_punt_dist: &mut Vec<f64> = benchmark.punt_distance.get_mut(&skill_diff).unwrap();
+                                let diff_punt_ret: &mut Vec<f64> = benchmark.punt_return_yards.get_mut(&skill_diff).unwrap();
+                                let yl_punt_dist: &mut Vec<f64> = benchmark.punt_distance_yl.get_mut(&yl_group).unwrap();
+                                let yl_punt_ret: &mut Vec<f64> = benchmark.punt_return_yards_yl.get_mut(&yl_group).unwrap();
                                 if !res.blocked() {
                                     diff_punt_dist.push(res.punt_yards() as f64);
                                     yl_punt_dist.push(res.punt_yards() as f64);
@@ -166,8 +538,8 @@ pub fn play_benchmark(_args: FbsimGamePlayBenchmarkArgs) -> Result<(), String> {
                                 }
                             },
                             PlayTypeResult::Kickoff(res) => {
-                                let diff_kick_dist: &mut Vec<f64> = kickoff_distance.get_mut(&skill_diff).unwrap();
-                                let diff_kick_ret: &mut Vec<f64> = kick_return_yards.get_mut(&skill_diff).unwrap();
+                                let diff_kick_dist: &mut Vec<f64> = benchmark.kickoff_distance.get_mut(&skill_diff).unwrap();
+                                let diff_kick_ret: &mut Vec<f64> = benchmark.kick_return_yards.get_mut(&skill_diff).unwrap();
                                 diff_kick_dist.push(res.kickoff_yards() as f64);
                                 if !(res.touchback() || res.out_of_bounds() || res.fair_catch()) {
                                     diff_kick_ret.push(res.kick_return_yards() as f64);
@@ -183,302 +555,30 @@ pub fn play_benchmark(_args: FbsimGamePlayBenchmarkArgs) -> Result<(), String> {
                 progress_bar.inc(1);
             }
         }
+        Ok(benchmark)
+    })?;
+    progress_bar.finish();
+
+    // Merge the results of each thread
+    let mut benchmark = PlayBenchmark::new(grid, iterations);
+    for result in results.into_iter() {
+        benchmark.merge(result);
     }
+    Ok(benchmark)
+}
 
-    // Duration
-    println!();
-    println!("############");
-    println!("# Duration #");
-    println!("############");
-
-    // Display mean, standard deviation play duration
-    let mut tw = TabWriter::new(stdout());
-    let mut duration_lines = String::from("Mean Duration\tStd Duration");
-    let mean = play_durations.clone().mean();
-    let std = play_durations.clone().std_dev();
-    let duration_line = format!("{:.4}\t{:.4}", mean, std);
-    duration_lines = duration_lines + "\n" + &duration_line;
-    println!();
-    println!("Play duration distribution:");
-    write!(&mut tw, "{}", &duration_lines).unwrap();
-    tw.flush().unwrap();
-    println!();
-
-    // Display mean, standard deviation post-play duration
-    let mut tw = TabWriter::new(stdout());
-    let mut duration_lines = String::from("Mean Duration\tStd Duration");
-    let mean = between_play_durations.clone().mean();
-    let std = between_play_durations.clone().std_dev();
-    let duration_line = format!("{:.4}\t{:.4}", mean, std);
-    duration_lines = duration_lines + "\n" + &duration_line;
-    println!();
-    println!("Post-play duration distribution:");
-    write!(&mut tw, "{}", &duration_lines).unwrap();
-    tw.flush().unwrap();
-    println!();
-
-    // Rushing
-    println!();
-    println!("###########");
-    println!("# Rushing #");
-    println!("###########");
-
-    // Display mean, standard deviation rushing
-    let mut tw = TabWriter::new(stdout());
-    let mut rushing_lines = String::from("Skill Diff\tMean Rushing\tStd Rushing");
-    for (diff, rs) in rushes.into_iter() {
-        let mean = rs.clone().mean();
-        let std = rs.clone().std_dev();
-        let rushing_line = format!("{}\t{:.4}\t{:.4}", diff, mean, std);
-        rushing_lines = rushing_lines + "\n" + &rushing_line;
-    }
-    println!();
-    println!("Rushing distribution:");
-    write!(&mut tw, "{}", &rushing_lines).unwrap();
-    tw.flush().unwrap();
-    println!();
-
-    // Field goals
-    println!();
-    println!("###############");
-    println!("# Field goals #");
-    println!("###############");
-
-    // Display field goal made percentage by skill diff
-    let mut tw = TabWriter::new(stdout());
-    let mut fg_made_lines = String::from("Skill Diff\tField Goal Made Percent");
-    for (diff, fgm) in fg_made.into_iter() {
-        let mean = fgm.clone().mean();
-        let fg_made_line = format!("{}\t{:.4}", diff, mean);
-        fg_made_lines = fg_made_lines + "\n" + &fg_made_line;
-    }
-    println!();
-    println!("Field goal made percentages (skill):");
-    write!(&mut tw, "{}", &fg_made_lines).unwrap();
-    tw.flush().unwrap();
-    println!();
-
-    // Display field goal made percentage by yard line
-    let mut tw = TabWriter::new(stdout());
-    let mut fg_made_yl_lines = String::from("Yard Line\tField Goal Made Percent");
-    for (yl, fgm) in fg_made_yl.into_iter() {
-        let mean = fgm.clone().mean();
-        let fg_made_yl_line = format!("{}\t{:.4}", yl, mean);
-        fg_made_yl_lines = fg_made_yl_lines + "\n" + &fg_made_yl_line;
-    }
-    println!();
-    println!("Field goal made percentages (yard line):");
-    write!(&mut tw, "{}", &fg_made_yl_lines).unwrap();
-    tw.flush().unwrap();
-    println!();
-
-    // Display field goal blocked percentage
-    let mut tw = TabWriter::new(stdout());
-    let mut fg_block_lines = String::from("Skill Diff\tField Goal Block Percent");
-    for (diff, fgb) in fg_blocks.into_iter() {
-        let mean = fgb.clone().mean();
-        let fg_block_line = format!("{}\t{:.4}", diff, mean);
-        fg_block_lines = fg_block_lines + "\n" + &fg_block_line;
-    }
-    println!();
-    println!("Field goal block percentages:");
-    write!(&mut tw, "{}", &fg_block_lines).unwrap();
-    tw.flush().unwrap();
-    println!();
-
-    // Display field goal blocked percentage by yard line
-    let mut tw = TabWriter::new(stdout());
-    let mut fg_blocked_yl_lines = String::from("Yard Line\tField Goal Made Percent");
-    for (yl, fgb) in fg_blocks_yl.into_iter() {
-        let mean = fgb.clone().mean();
-        let fg_blocked_yl_line = format!("{}\t{:.4}", yl, mean);
-        fg_blocked_yl_lines = fg_blocked_yl_lines + "\n" + &fg_blocked_yl_line;
-    }
-    println!();
-    println!("Field goal blocked percentages (yard line):");
-    write!(&mut tw, "{}", &fg_blocked_yl_lines).unwrap();
-    tw.flush().unwrap();
-    println!();
-
-    // Kickoffs
-    println!();
-    println!("############");
-    println!("# Kickoffs #");
-    println!("############");
-
-    // Display kickoff distance by skill diff
-    let mut tw = TabWriter::new(stdout());
-    let mut kick_dist_lines = String::from("Skill Diff\tMean Kickoff Distance\tStd Kickoff Distance");
-    for (diff, dist) in kickoff_distance.into_iter() {
-        let mean = dist.clone().mean();
-        let std = dist.clone().std_dev();
-        let kick_dist_line = format!("{}\t{:.4}\t{:.4}", diff, mean, std);
-        kick_dist_lines = kick_dist_lines + "\n" + &kick_dist_line;
-    }
-    println!();
-    println!("Kickoff distance distribution (skill):");
-    write!(&mut tw, "{}", &kick_dist_lines).unwrap();
-    tw.flush().unwrap();
-    println!();
-
-    // Display kickoff return yards by skill diff
-    let mut tw = TabWriter::new(stdout());
-    let mut kick_ret_lines = String::from("Skill Diff\tMean Kick Return Yards\tStd Kick Return Yards");
-    for (diff, ret) in kick_return_yards.into_iter() {
-        let mean = ret.clone().mean();
-        let std = ret.clone().std_dev();
-        let kick_ret_line = format!("{}\t{:.4}\t{:.4}", diff, mean, std);
-        kick_ret_lines = kick_ret_lines + "\n" + &kick_ret_line;
-    }
-    println!();
-    println!("Kick return yards distribution (skill):");
-    write!(&mut tw, "{}", &kick_ret_lines).unwrap();
-    tw.flush().unwrap();
-    println!();
-
-    // Punts
-    println!();
-    println!("#########");
-    println!("# Punts #");
-    println!("#########");
-
-    // Display punt distance by skill diff
-    let mut tw = TabWriter::new(stdout());
-    let mut punt_dist_lines = String::from("Skill Diff\tMean Punt Distance\tStd Punt Distance");
-    for (diff, dist) in punt_distance.into_iter() {
-        let mean = dist.clone().mean();
-        let std = dist.clone().std_dev();
-        let punt_dist_line = format!("{}\t{:.4}\t{:.4}", diff, mean, std);
-        punt_dist_lines = punt_dist_lines + "\n" + &punt_dist_line;
-    }
-    println!();
-    println!("Punt distance distribution (skill):");
-    write!(&mut tw, "{}", &punt_dist_lines).unwrap();
-    tw.flush().unwrap();
-    println!();
-
-    // Display punt distance by yard line
-    let mut tw = TabWriter::new(stdout());
-    let mut punt_dist_yl_lines = String::from("Yard Line\tMean Punt Distance\tStd Punt Distance");
-    for (yl, dist) in punt_distance_yl.into_iter() {
-        let mean = dist.clone().mean();
-        let std = dist.clone().std_dev();
-        let punt_dist_yl_line = format!("{}\t{:.4}\t{:.4}", yl, mean, std);
-        punt_dist_yl_lines = punt_dist_yl_lines + "\n" + &punt_dist_yl_line;
-    }
-    println!();
-    println!("Punt distance distribution (yard line):");
-    write!(&mut tw, "{}", &punt_dist_yl_lines).unwrap();
-    tw.flush().unwrap();
-    println!();
-
-    // Display punt return yards by skill diff
-    let mut tw = TabWriter::new(stdout());
-    let mut punt_ret_lines = String::from("Skill Diff\tMean Punt Return Yards\tStd Punt Return Yards");
-    for (diff, ret) in punt_return_yards.into_iter() {
-        let mean = ret.clone().mean();
-        let std = ret.clone().std_dev();
-        let punt_ret_line = format!("{}\t{:.4}\t{:.4}", diff, mean, std);
-        punt_ret_lines = punt_ret_lines + "\n" + &punt_ret_line;
-    }
-    println!();
-    println!("Punt return yards distribution (skill):");
-    write!(&mut tw, "{}", &punt_ret_lines).unwrap();
-    tw.flush().unwrap();
-    println!();
-
-    // Display punt return yards by yard line
-    let mut tw = TabWriter::new(stdout());
-    let mut punt_ret_yl_lines = String::from("Yard Line\tMean Punt Return Yards\tStd Punt Return Yards");
-    for (yl, ret) in punt_return_yards_yl.into_iter() {
-        let mean = ret.clone().mean();
-        let std = ret.clone().std_dev();
-        let punt_ret_yl_line = format!("{}\t{:.4}\t{:.4}", yl, mean, std);
-        punt_ret_yl_lines = punt_ret_yl_lines + "\n" + &punt_ret_yl_line;
-    }
-    println!();
-    println!("Punt return yards distribution (yard line):");
-    write!(&mut tw, "{}", &punt_ret_yl_lines).unwrap();
-    tw.flush().unwrap();
-    println!();
-
-    // Passing
-    println!();
-    println!("###########");
-    println!("# Passing #");
-    println!("###########");
-
-    // Display mean, standard deviation passing
-    let mut tw = TabWriter::new(stdout());
-    let mut passing_lines = String::from("Skill Diff\tMean Passing\tStd Passing");
-    for (diff, ps) in passes.into_iter() {
-        let mean = ps.clone().mean();
-        let std = ps.clone().std_dev();
-        let passing_line = format!("{}\t{:.4}\t{:.4}", diff, mean, std);
-        passing_lines = passing_lines + "\n" + &passing_line;
-    }
-    println!();
-    println!("Passing distribution:");
-    write!(&mut tw, "{}", &passing_lines).unwrap();
-    tw.flush().unwrap();
-    println!();
-
-    // Display mean, standard deviation pass distances
-    let mut tw = TabWriter::new(stdout());
-    let mut pass_distance_lines = String::from("Skill Diff\tMean Pass Distance\tStd Pass Distance");
-    for (diff, dists) in pass_distances.into_iter() {
-        let mean = dists.clone().mean();
-        let std = dists.clone().std_dev();
-        let pass_distance_line = format!("{}\t{:.4}\t{:.4}", diff, mean, std);
-        pass_distance_lines = pass_distance_lines + "\n" + &pass_distance_line;
-    }
-    println!();
-    println!("Pass distance distribution:");
-    write!(&mut tw, "{}", &pass_distance_lines).unwrap();
-    tw.flush().unwrap();
-    println!();
-
-    // Display mean, standard deviation yards after catch
-    let mut tw = TabWriter::new(stdout());
-    let mut yac_lines = String::from("Skill Diff\tMean Yards After Catch\tStd Yards After Catch");
-    for (diff, yacs) in yac.into_iter() {
-        let mean = yacs.clone().mean();
-        let std = yacs.clone().std_dev();
-        let yac_line = format!("{}\t{:.4}\t{:.4}", diff, mean, std);
-        yac_lines = yac_lines + "\n" + &yac_line;
-    }
-    println!();
-    println!("Yards after catch distribution:");
-    write!(&mut tw, "{}", &yac_lines).unwrap();
-    tw.flush().unwrap();
-    println!();
-
-    // Display completion percentages
-    let mut tw = TabWriter::new(stdout());
-    let mut comp_lines = String::from("Skill Diff\tCompletion Percentage");
-    for (diff, comps) in completions.into_iter() {
-        let mean = comps.clone().mean();
-        let comp_line = format!("{}\t{:.4}", diff, mean);
-        comp_lines = comp_lines + "\n" + &comp_line;
-    }
-    println!();
-    println!("Completion percentages:");
-    write!(&mut tw, "{}", &comp_lines).unwrap();
-    tw.flush().unwrap();
-    println!();
-
-    // Display interception percentages
-    let mut tw = TabWriter::new(stdout());
-    let mut int_lines = String::from("Skill Diff\tInterception Percentage");
-    for (diff, ints) in interceptions.into_iter() {
-        let mean = ints.clone().mean();
-        let int_line = format!("{}\t{:.4}", diff, mean);
-        int_lines = int_lines + "\n" + &int_line;
-    }
-    println!();
-    println!("Interception percentages:");
-    write!(&mut tw, "{}", &int_lines).unwrap();
-    tw.flush().unwrap();
-    println!();
-    Ok(())
+pub fn play_benchmark(args: FbsimGamePlayBenchmarkArgs, seed: Option<u64>) -> Result<(), String> {
+    // Load the benchmark arguments and run the benchmark
+    let iterations: usize = args.iterations.unwrap_or(100);
+    let grid = SkillGrid::new(args.skill_step.unwrap_or(10))?;
+    let threads: usize = args.threads.unwrap_or(1);
+    let benchmark = sim_play_benchmark(&grid, iterations, threads, seed)?;
+
+    // Write the benchmark results in the given output format
+    write_benchmark_output(
+        &args.output_format,
+        &args.output_file,
+        || benchmark.tables(),
+        || benchmark.report(&grid)
+    )
 }
@@ -0,0 +1,70 @@
+use std::io::{Stdout, Write};
+use std::{thread, time};
+
+use crossterm::{terminal, cursor, QueueableCommand};
+
+use fbsim_core::game::context::GameContext;
+use fbsim_core::game::play::{Drive, Game, Play};
+use fbsim_core::game::play::result::{PlayResult, PlayTypeResult};
+
+use crate::game::boxscore::BoxScore;
+
+/// Get the time to wait after displaying a play during live playback
+pub fn play_wait_time(play: &Play, playback_speed: f64) -> time::Duration {
+    let play_duration = play.result().play_duration();
+    let post_play_duration = match play.post_play() {
+        PlayTypeResult::BetweenPlay(res) => 20.max(res.duration()),
+        _ => 30
+    };
+    let duration = play_duration + post_play_duration;
+    let wait_time = (duration * 250) as f64 / playback_speed;
+    time::Duration::from_millis(wait_time.round().abs() as u64)
+}
+
+/// Display the latest state of a drive during live playback
+///
+/// Writes the drive to stdout, waits based on the duration of its latest
+/// play, then moves the cursor back up so the drive is rewritten in place
+/// on the next play unless the drive is complete
+pub fn playback_drive(stdout: &mut Stdout, drive: &Drive, playback_speed: f64) -> Result<(), String> {
+    // Display the drive
+    let drive_str = format!("{}", drive);
+    let drive_str_len = drive_str.matches("\n").count() as u16;
+    if stdout.write_all(drive_str.as_bytes()).is_err() {
+        return Err(String::from("Failed to write drive to stdout"));
+    }
+    if stdout.flush().is_err() {
+        return Err(String::from("Failed to flush stdout"));
+    }
+
+    // Wait based on the duration of the play
+    let play = match drive.plays().last() {
+        Some(p) => p,
+        None => return Err(String::from("No plays found in current drive"))
+    };
+    thread::sleep(play_wait_time(play, playback_speed));
+
+    // Reset the cursor if drive is not complete
+    if !drive.complete() {
+        let errmsg = String::from("Failed to reset cursor");
+        if stdout.queue(cursor::MoveUp(drive_str_len)).is_err() {
+            return Err(errmsg);
+        }
+        if stdout.queue(cursor::MoveToColumn(0)).is_err() {
+            return Err(errmsg);
+        }
+        if stdout.queue(terminal::Clear(terminal::ClearType::FromCursorDown)).is_err() {
+            return Err(errmsg);
+        }
+    } else {
+        println!("\n");
+    }
+    Ok(())
+}
+
+/// Display the game-over message and final stats for a game
+pub fn display_game_over(context: &GameContext, game: &Game) {
+    println!("{} Game over", context);
+    println!();
+    println!("{}", BoxScore::new(game, context));
+}
@@ -0,0 +1,150 @@
+use std::fs;
+use std::io::stdout;
+
+use fbsim_core::game::context::GameContext;
+use fbsim_core::game::play::{Drive, DriveResult, Game};
+
+use crate::cli::game::FbsimGameReplayArgs;
+use crate::game::playback::{display_game_over, playback_drive};
+
+use serde::{Deserialize, Serialize};
+use serde_json;
+
+/// A recorded game, including every drive and play of the game along with
+/// the context each play was run from, as well as the final game context
+#[derive(Serialize, Deserialize)]
+pub struct GameRecording {
+    game: Game,
+    context: GameContext
+}
+
+impl GameRecording {
+    pub fn new(game: Game, context: GameContext) -> GameRecording {
+        GameRecording{
+            game,
+            context
+        }
+    }
+}
+
+/// Get the state of a drive as it was after its first `num_plays` plays
+///
+/// A drive cannot be built from its plays, so the partial drive is rebuilt
+/// from the serialized fields of the drive
+fn partial_drive(drive: &Drive, num_plays: usize) -> Result<Drive, String> {
+    // The full drive is displayed as-is
+    if num_plays >= drive.plays().len() {
+        return Ok(drive.clone());
+    }
+
+    // Otherwise truncate its plays and mark it as incomplete. A touchdown
+    // drive takes its result on the scoring play, before the extra point
+    let touchdown = matches!(
+        drive.result(),
+        DriveResult::Touchdown | DriveResult::PickSix | DriveResult::ScoopAndScore
+    );
+    let result = if touchdown && num_plays + 1 == drive.plays().len() {
+        *drive.result()
+    } else {
+        DriveResult::None
+    };
+    let mut drive_value = match serde_json::to_value(drive) {
+        Ok(v) => v,
+        Err(e) => return Err(format!("Error serializing drive: {}", e))
+    };
+    let plays: Vec<serde_json::Value> = drive.plays()[..num_plays].iter()
+        .map(serde_json::to_value)
+        .collect::<Result<_, _>>()
+        .map_err(|e| format!("Error serializing play: {}", e))?;
+    drive_value["plays"] = serde_json::Value::Array(plays);
+    drive_value["result"] = match serde_json::to_value(result) {
+        Ok(v) => v,
+        Err(e) => return Err(format!("Error serializing drive result: {}", e))
+    };
+    drive_value["complete"] = serde_json::Value::Bool(false);
+    match serde_json::from_value(drive_value) {
+        Ok(d) => Ok(d),
+        Err(e) => Err(format!("Error deserializing partial drive: {}", e))
+    }
+}
+
+pub fn game_replay(args: FbsimGameReplayArgs) -> Result<(), String> {
+    // Load the game recording from its file
+    let recording_file_res = &fs::read_to_string(&args.recording);
+    let recording_file = match recording_file_res {
+        Ok(file) => file,
+        Err(e) => return Err(format!("Error loading game recording file: {}", e)),
+    };
+    let recording: GameRecording = match serde_json::from_str(recording_file) {
+        Ok(r) => r,
+        Err(e) => return Err(format!("Error loading game recording: {}", e)),
+    };
+
+    // Load the playback speed argument
+    let playback_speed: f64 = args.playback_speed.unwrap_or(2.0);
+
+    // Play back each drive of the game one play at a time
+    let mut stdout = stdout();
+    for drive in recording.game.drives().iter() {
+        for num_plays in 1..=drive.plays().len() {
+            let drive_state = partial_drive(drive, num_plays)?;
+            playback_drive(&mut stdout, &drive_state, playback_speed)?;
+        }
+    }
+
+    // Print game-over message and final stats
+    display_game_over(&recording.context, &recording.game);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::rng::new_rng;
+
+    use fbsim_core::game::context::GameContextBuilder;
+    use fbsim_core::game::play::DriveSimulator;
+    use fbsim_core::team::FootballTeam;
+
+    #[test]
+    fn test_partial_drive() {
+        // The partial drive is rebuilt from the drive's serialized fields
+        let home_team = FootballTeam::from_overalls("Home Team", "HOME", 70, 50).unwrap();
+        let away_team = FootballTeam::from_overalls("Away Team", "AWAY", 50, 50).unwrap();
+        let context = GameContextBuilder::new()
+            .down(1)
+            .distance(10)
+            .yard_line(25)
+            .next_play_kickoff(false)
+            .build()
+            .unwrap();
+        let mut rng = new_rng(Some(0)).unwrap();
+        let mut touchdowns = 0;
+        for _ in 0..50 {
+            let (drive, _) = DriveSimulator::new().sim(&home_team, &away_team, context.clone(), &mut rng);
+            let drive_value = serde_json::to_value(&drive).unwrap();
+            let keys: Vec<&String> = drive_value.as_object().unwrap().keys().collect();
+            assert_eq!(keys, vec!["complete", "plays", "result"]);
+
+            // Each partial drive holds the drive's first plays and is incomplete
+            let len = drive.plays().len();
+            let touchdown = *drive.result() == DriveResult::Touchdown;
+            for num_plays in 1..len {
+                let partial = partial_drive(&drive, num_plays).unwrap();
+                assert_eq!(partial.plays()[..], drive.plays()[..num_plays]);
+                assert!(!partial.complete());
+                if touchdown && num_plays + 1 == len {
+                    assert_eq!(partial.result(), drive.result());
+                } else {
+                    assert_eq!(*partial.result(), DriveResult::None);
+                }
+            }
+            touchdowns += touchdown as usize;
+
+            // The partial drive of every play is the drive itself
+            assert_eq!(partial_drive(&drive, len).unwrap(), drive);
+        }
+        assert!(touchdowns > 0);
+    }
+}
@@ -0,0 +1,146 @@
+use std::collections::{BTreeMap, HashMap};
+use std::io::{Write, stdout};
+
+use indicatif::ProgressBar;
+use rand::Rng;
+use statrs::statistics::Statistics;
+use tabwriter::TabWriter;
+
+use fbsim_core::game::play::GameSimulator;
+use fbsim_core::game::context::{GameContext, GameContextBuilder};
+use fbsim_core::team::FootballTeam;
+
+use crate::benchmark::format_std_dev;
+use crate::cli::game::FbsimGameSeriesArgs;
+use crate::rng::new_rng;
+use crate::team::load::load_team;
+
+/// The number of most common final scores to display
+const NUM_COMMON_SCORES: usize = 10;
+
+pub fn game_series(args: FbsimGameSeriesArgs, seed: Option<u64>) -> Result<(), String> {
+    // Load the home and away teams from their files
+    let home_team: FootballTeam = match load_team(&args.home) {
+        Ok(team) => team,
+        Err(e) => return Err(format!("Error loading home team: {}", e)),
+    };
+    let away_team: FootballTeam = match load_team(&args.away) {
+        Ok(team) => team,
+        Err(e) => return Err(format!("Error loading away team: {}", e)),
+    };
+
+    // Load the number of games and neutral site arguments
+    let num_games: usize = args.games.unwrap_or(1000);
+    if num_games == 0 {
+        return Err(String::from("Number of games must be greater than 0"));
+    }
+    let neutral_site: bool = args.neutral_site.unwrap_or(false);
+
+    // Instantiate the simulator and RNG
+    let game_sim = GameSimulator::new();
+    let mut rng = new_rng(seed)?;
+
+    // Instantiate the score, margin and final score trackers
+    let mut home_wins: usize = 0;
+    let mut ties: usize = 0;
+    let mut home_scores: Vec<f64> = Vec::new();
+    let mut away_scores: Vec<f64> = Vec::new();
+    let mut margin_freq: BTreeMap<i32, u32> = BTreeMap::new();
+    let mut final_score_freq: HashMap<(u32, u32), u32> = HashMap::new();
+
+    // Instantiate a progress bar for series progress
+    let progress_bar = ProgressBar::new(num_games as u64);
+
+    // Simulate each game of the series
+    for _ in 0..num_games {
+        let home_opening_kickoff: bool = rng.gen::<bool>();
+        let context: GameContext = GameContextBuilder::new()
+            .home_team_short(home_team.short_name())
+            .away_team_short(away_team.short_name())
+            .home_possession(!home_opening_kickoff)
+            .home_positive_direction(!home_opening_kickoff)
+            .home_opening_kickoff(home_opening_kickoff)
+            .neutral_site(neutral_site)
+            .build()
+            .unwrap();
+        let (_game, context) = match game_sim.sim(&home_team, &away_team, context, &mut rng) {
+            Ok((g, c)) => (g, c),
+            Err(e) => return Err(format!("Error simulating game: {}", e))
+        };
+
+        // Track the outcome, scores, margin and final score
+        let home_score = context.home_score();
+        let away_score = context.away_score();
+        if home_score > away_score {
+            home_wins += 1;
+        } else if home_score == away_score {
+            ties += 1;
+        }
+        home_scores.push(home_score as f64);
+        away_scores.push(away_score as f64);
+        *margin_freq.entry(home_score as i32 - away_score as i32).or_insert(0) += 1;
+        *final_score_freq.entry((home_score, away_score)).or_insert(0) += 1;
+
+        // Increment the progress bar
+        progress_bar.inc(1);
+    }
+    progress_bar.finish();
+
+    // Display the series outcome
+    let away_wins = num_games - home_wins - ties;
+    let mut tw = TabWriter::new(stdout());
+    let outcome_lines = format!(
+        "Outcome\tFrequency\tCount\nHome win ({})\t{:.4}%\t{}\nAway win ({})\t{:.4}%\t{}\nTie\t{:.4}%\t{}",
+        home_team.short_name(), home_wins as f64 * 100_f64 / num_games as f64, home_wins,
+        away_team.short_name(), away_wins as f64 * 100_f64 / num_games as f64, away_wins,
+        ties as f64 * 100_f64 / num_games as f64, ties
+    );
+    println!();
+    println!("{} @ {} ({} games)", away_team.name(), home_team.name(), num_games);
+    println!();
+    println!("Series outcome:");
+    write!(&mut tw, "{}", &outcome_lines).unwrap();
+    tw.flush().unwrap();
+    println!();
+
+    // Display the mean and standard deviation of each team's score
+    let score_lines = format!(
+        "Team\tMean Score\tStd Score\nHome ({})\t{:.4}\t{}\nAway ({})\t{:.4}\t{}",
+        home_team.short_name(), home_scores.clone().mean(), format_std_dev(&home_scores),
+        away_team.short_name(), away_scores.clone().mean(), format_std_dev(&away_scores)
+    );
+    println!();
+    println!("Score distribution:");
+    write!(&mut tw, "{}", &score_lines).unwrap();
+    tw.flush().unwrap();
+    println!();
+
+    // Display the distribution of the home team's margin of victory
+    let mut margin_lines = String::from("Home Margin\tFrequency\tCount");
+    for (margin, count) in margin_freq.into_iter() {
+        let freq = count as f64 / num_games as f64;
+        let margin_line = format!("{}\t{:.4}%\t{}", margin, freq * 100_f64, count);
+        margin_lines = margin_lines + "\n" + &margin_line;
+    }
+    println!();
+    println!("Margin distribution:");
+    write!(&mut tw, "{}", &margin_lines).unwrap();
+    tw.flush().unwrap();
+    println!();
+
+    // Display the most common final scores
+    let mut final_scores: Vec<((u32, u32), u32)> = final_score_freq.into_iter().collect();
+    final_scores.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(&b.0)));
+    let mut final_score_lines = String::from("Home Score\tAway Score\tFrequency\tCount");
+    for ((home_score, away_score), count) in final_scores.into_iter().take(NUM_COMMON_SCORES) {
+        let freq = count as f64 / num_games as f64;
+        let final_score_line = format!("{}\t{}\t{:.4}%\t{}", home_score, away_score, freq * 100_f64, count);
+        final_score_lines = final_score_lines + "\n" + &final_score_line;
+    }
+    println!();
+    println!("Most common final scores:");
+    write!(&mut tw, "{}", &final_score_lines).unwrap();
+    tw.flush().unwrap();
+    println!();
+    Ok(())
+}
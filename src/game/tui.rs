@@ -0,0 +1,178 @@
+use std::collections::VecDeque;
+use std::io::{Stdout, Write};
+use std::thread;
+
+use crossterm::{terminal, cursor, ExecutableCommand, QueueableCommand};
+
+use fbsim_core::game::context::GameContext;
+use fbsim_core::game::play::Play;
+use fbsim_core::game::play::context::PlayContext;
+
+use crate::game::playback::play_wait_time;
+
+/// Number of columns used to draw the 100 yards between the goal lines
+const FIELD_WIDTH: usize = 50;
+
+/// Maximum number of plays retained in the scrolling play log
+const MAX_PLAY_LOG: usize = 200;
+
+/// A full-screen scoreboard for live game playback
+///
+/// Draws a persistent scorebug, an ASCII field showing the ball position and
+/// a scrolling play log in the terminal's alternate screen. The alternate
+/// screen is left when the scoreboard is dropped.
+pub struct Scoreboard {
+    play_log: VecDeque<String>
+}
+
+impl Scoreboard {
+    /// Enter the alternate screen and initialize an empty scoreboard
+    pub fn new(stdout: &mut Stdout) -> Result<Scoreboard, String> {
+        let errmsg = String::from("Failed to initialize scoreboard");
+        if stdout.execute(terminal::EnterAlternateScreen).is_err() {
+            return Err(errmsg);
+        }
+        if stdout.execute(cursor::Hide).is_err() {
+            return Err(errmsg);
+        }
+        Ok(
+            Scoreboard{
+                play_log: VecDeque::new()
+            }
+        )
+    }
+
+    /// Redraw the scoreboard after a play, then wait based on its duration
+    pub fn playback_play(&mut self, stdout: &mut Stdout, play: &Play, context: &GameContext, playback_speed: f64) -> Result<(), String> {
+        // Add the play to the play log
+        self.play_log.push_back(format!("{}", play));
+        if self.play_log.len() > MAX_PLAY_LOG {
+            self.play_log.pop_front();
+        }
+
+        // Redraw the scoreboard and wait based on the duration of the play
+        self.draw(stdout, context)?;
+        thread::sleep(play_wait_time(play, playback_speed));
+        Ok(())
+    }
+
+    /// Draw the scorebug, field and play log for the given context
+    fn draw(&self, stdout: &mut Stdout, context: &GameContext) -> Result<(), String> {
+        // Fit the play log to the height of the terminal
+        let (width, height) = terminal::size().unwrap_or((80, 24));
+        let mut lines = scorebug(context);
+        lines.push(String::new());
+        lines.extend(field(context));
+        lines.push(String::new());
+        lines.push(String::from("Play log"));
+        let log_rows = (height as usize).saturating_sub(lines.len() + 1);
+        let skip = self.play_log.len().saturating_sub(log_rows);
+        lines.extend(self.play_log.iter().skip(skip).cloned());
+
+        // Draw each line, truncated to the width of the terminal
+        let errmsg = String::from("Failed to draw scoreboard");
+        if stdout.queue(terminal::Clear(terminal::ClearType::All)).is_err() {
+            return Err(errmsg);
+        }
+        for (i, line) in lines.iter().enumerate() {
+            let line: String = line.chars().take(width as usize).collect();
+            if stdout.queue(cursor::MoveTo(0, i as u16)).is_err() {
+                return Err(errmsg);
+            }
+            if stdout.write_all(line.as_bytes()).is_err() {
+                return Err(errmsg);
+            }
+        }
+        if stdout.flush().is_err() {
+            return Err(String::from("Failed to flush stdout"));
+        }
+        Ok(())
+    }
+}
+
+impl Drop for Scoreboard {
+    fn drop(&mut self) {
+        let mut stdout = std::io::stdout();
+        let _ = stdout.execute(cursor::Show);
+        let _ = stdout.execute(terminal::LeaveAlternateScreen);
+    }
+}
+
+/// Format the scorebug for the given context
+fn scorebug(context: &GameContext) -> Vec<String> {
+    // Mark the team in possession
+    let (away_poss, home_poss) = if context.home_possession() {
+        (" ", "*")
+    } else {
+        ("*", " ")
+    };
+
+    // Split the play context into the clock and the down and distance
+    let situation = if context.game_over() {
+        String::from("FINAL")
+    } else {
+        let play_context = format!("{}", PlayContext::from(context));
+        match play_context.trim_start_matches('[').split_once("] ") {
+            Some((clock, down_dist)) => format!("{}  |  {}", clock, down_dist),
+            None => play_context
+        }
+    };
+    let scorebug = format!(
+        "| {}{:<4} {:>3}  @  {}{:<4} {:>3}  |  {} |",
+        away_poss,
+        context.away_team_short(),
+        context.away_score(),
+        home_poss,
+        context.home_team_short(),
+        context.home_score(),
+        situation
+    );
+    let border = format!("+{}+", "-".repeat(scorebug.chars().count() - 2));
+    vec![border.clone(), scorebug, border]
+}
+
+/// Draw an ASCII field with the ball position for the given context
+fn field(context: &GameContext) -> Vec<String> {
+    // The team driving in the positive direction defends the left end zone
+    let (left_team, right_team) = if context.home_positive_direction() {
+        (context.home_team_short(), context.away_team_short())
+    } else {
+        (context.away_team_short(), context.home_team_short())
+    };
+    let left_end_zone = format!("[{:^4}]", left_team);
+    let right_end_zone = format!("[{:^4}]", right_team);
+    let pad = " ".repeat(left_end_zone.len());
+
+    // Yard markers every 10 yards
+    let mut markers = vec![' '; FIELD_WIDTH + 1];
+    for yards in (10..100).step_by(10) {
+        let label = format!("{}", if yards > 50 { 100 - yards } else { yards });
+        let col = yards * FIELD_WIDTH / 100 - label.len() / 2;
+        for (i, c) in label.chars().enumerate() {
+            if let Some(m) = markers.get_mut(col + i) {
+                *m = c;
+            }
+        }
+    }
+
+    // Field lines every 10 yards
+    let mut turf = vec!['.'; FIELD_WIDTH + 1];
+    for yards in (0..=100).step_by(10) {
+        turf[yards * FIELD_WIDTH / 100] = '|';
+    }
+
+    // Ball position and the offense's direction of travel
+    let mut ball = vec![' '; FIELD_WIDTH + 1];
+    let ball_col = (context.yard_line().min(100) as usize) * FIELD_WIDTH / 100;
+    ball[ball_col] = if context.home_possession() == context.home_positive_direction() {
+        '>'
+    } else {
+        '<'
+    };
+
+    vec![
+        format!("{}{}", pad, markers.iter().collect::<String>()),
+        format!("{}{}{}", left_end_zone, turf.iter().collect::<String>(), right_end_zone),
+        format!("{}{}", pad, ball.iter().collect::<String>())
+    ]
+}
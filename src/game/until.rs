@@ -0,0 +1,143 @@
+use std::str::FromStr;
+
+use fbsim_core::game::context::GameContext;
+
+/// A condition at which to stop simulating plays or drives
+#[derive(Debug, PartialEq)]
+pub enum StopCondition {
+    EndOfQuarter,
+    EndOfHalf,
+    Score,
+    PossessionChange,
+    GameOver
+}
+
+impl FromStr for StopCondition {
+    type Err = String;
+    fn from_str(input: &str) -> Result<StopCondition, Self::Err> {
+        match input {
+            "end-of-quarter"    => Ok(StopCondition::EndOfQuarter),
+            "end-of-half"       => Ok(StopCondition::EndOfHalf),
+            "score"             => Ok(StopCondition::Score),
+            "possession-change" => Ok(StopCondition::PossessionChange),
+            "game-over"         => Ok(StopCondition::GameOver),
+            _ => Err(
+                format!(
+                    "Invalid stop condition, expected end-of-quarter, end-of-half, score, possession-change or game-over: {}",
+                    input
+                )
+            )
+        }
+    }
+}
+
+/// The half of a quarter, with each overtime period as its own half
+fn half(quarter: u32) -> u32 {
+    if quarter <= 4 {
+        quarter.div_ceil(2)
+    } else {
+        quarter - 2
+    }
+}
+
+impl StopCondition {
+    /// Whether the condition was reached between two contexts
+    pub fn reached(&self, before: &GameContext, after: &GameContext) -> bool {
+        if after.game_over() {
+            return true;
+        }
+        match self {
+            StopCondition::EndOfQuarter => after.quarter() != before.quarter(),
+            StopCondition::EndOfHalf => half(after.quarter()) != half(before.quarter()),
+            StopCondition::Score => {
+                after.home_score() != before.home_score() ||
+                    after.away_score() != before.away_score()
+            },
+            StopCondition::PossessionChange => after.home_possession() != before.home_possession(),
+            StopCondition::GameOver => false
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use fbsim_core::game::context::GameContextBuilder;
+
+    const CONDITIONS: [StopCondition; 5] = [
+        StopCondition::EndOfQuarter,
+        StopCondition::EndOfHalf,
+        StopCondition::Score,
+        StopCondition::PossessionChange,
+        StopCondition::GameOver
+    ];
+
+    /// A context at the start of the given quarter
+    fn quarter_start(quarter: u32) -> GameContextBuilder {
+        let half_seconds = if quarter % 2 == 1 && quarter < 4 { 1800 } else { 900 };
+        GameContextBuilder::new()
+            .quarter(quarter)
+            .half_seconds(half_seconds)
+    }
+
+    /// The conditions reached between two contexts
+    fn reached(before: GameContextBuilder, after: GameContextBuilder) -> Vec<&'static StopCondition> {
+        let (before, after) = (before.build().unwrap(), after.build().unwrap());
+        CONDITIONS.iter().filter(|c| c.reached(&before, &after)).collect()
+    }
+
+    #[test]
+    fn test_half() {
+        assert_eq!([1, 2, 3, 4].map(half), [1, 1, 2, 2]);
+        assert_eq!(half(5), 3);
+        assert_eq!(half(6), 4);
+    }
+
+    #[test]
+    fn test_reached_nothing() {
+        assert!(reached(quarter_start(1), quarter_start(1)).is_empty());
+    }
+
+    #[test]
+    fn test_reached_end_of_quarter() {
+        assert_eq!(reached(quarter_start(1), quarter_start(2)), vec![&StopCondition::EndOfQuarter]);
+        assert_eq!(reached(quarter_start(3), quarter_start(4)), vec![&StopCondition::EndOfQuarter]);
+    }
+
+    #[test]
+    fn test_reached_end_of_half() {
+        let end_of_half = vec![&StopCondition::EndOfQuarter, &StopCondition::EndOfHalf];
+        assert_eq!(reached(quarter_start(2), quarter_start(3)), end_of_half);
+        assert_eq!(reached(quarter_start(4), quarter_start(5)), end_of_half);
+        assert_eq!(reached(quarter_start(5), quarter_start(6)), end_of_half);
+    }
+
+    #[test]
+    fn test_reached_score() {
+        assert_eq!(
+            reached(quarter_start(1), quarter_start(1).home_score(7)),
+            vec![&StopCondition::Score]
+        );
+        assert_eq!(
+            reached(quarter_start(5).away_score(10), quarter_start(5).away_score(13)),
+            vec![&StopCondition::Score]
+        );
+    }
+
+    #[test]
+    fn test_reached_possession_change() {
+        assert_eq!(
+            reached(quarter_start(1).home_possession(true), quarter_start(1).home_possession(false)),
+            vec![&StopCondition::PossessionChange]
+        );
+    }
+
+    #[test]
+    fn test_reached_game_over() {
+        for quarter in [4, 5] {
+            let after = quarter_start(quarter).half_seconds(0).home_score(3).game_over(true);
+            assert_eq!(reached(quarter_start(quarter), after).len(), CONDITIONS.len());
+        }
+    }
+}
@@ -0,0 +1,232 @@
+use std::collections::HashMap;
+
+use indicatif::ProgressBar;
+use rand::rngs::StdRng;
+use statrs::distribution::{ContinuousCDF, Normal};
+use statrs::statistics::Statistics;
+
+use fbsim_core::game::play::GameSimulator;
+use fbsim_core::game::play::context::PlayContext;
+use fbsim_core::game::context::GameContext;
+use fbsim_core::team::FootballTeam;
+
+use crate::benchmark::{format_std_dev, tabulate};
+use crate::cli::game::FbsimGameWinprobArgs;
+use crate::game::context::load_context;
+use crate::rng::new_rng;
+use crate::team::load::load_team;
+
+/// The number of most common final scores to display
+const NUM_COMMON_SCORES: usize = 10;
+
+/// The outcomes of simulating the remainder of a game many times
+#[derive(Default)]
+pub struct WinProbability {
+    home_wins: usize,
+    away_wins: usize,
+    ties: usize,
+    home_scores: Vec<f64>,
+    away_scores: Vec<f64>,
+    final_score_freq: HashMap<(u32, u32), u32>
+}
+
+impl WinProbability {
+    /// Simulate the remainder of a game from a context the given number of times
+    pub fn sim(home: &FootballTeam, away: &FootballTeam, context: &GameContext, trials: usize, rng: &mut StdRng, progress_bar: &ProgressBar) -> Result<WinProbability, String> {
+        if trials == 0 {
+            return Err(String::from("Number of trials must be greater than 0"));
+        }
+        let game_sim = GameSimulator::new();
+        let mut win_prob = WinProbability::default();
+        for _ in 0..trials {
+            // Finish the game from the context and track its outcome
+            let (_game, final_context) = match game_sim.sim(home, away, context.clone(), rng) {
+                Ok((g, c)) => (g, c),
+                Err(e) => return Err(format!("Error simulating game: {}", e))
+            };
+            win_prob.record(&final_context);
+
+            // Increment the progress bar
+            progress_bar.inc(1);
+        }
+        Ok(win_prob)
+    }
+
+    /// Record the outcome and final score of a finished game
+    pub fn record(&mut self, final_context: &GameContext) {
+        let home_score = final_context.home_score();
+        let away_score = final_context.away_score();
+        if home_score > away_score {
+            self.home_wins += 1;
+        } else if home_score < away_score {
+            self.away_wins += 1;
+        } else {
+            self.ties += 1;
+        }
+        self.home_scores.push(home_score as f64);
+        self.away_scores.push(away_score as f64);
+        *self.final_score_freq.entry((home_score, away_score)).or_insert(0) += 1;
+    }
+
+    /// The number of simulated games won by the home team
+    pub fn home_wins(&self) -> usize {
+        self.home_wins
+    }
+
+    /// The number of simulated games won by the away team
+    pub fn away_wins(&self) -> usize {
+        self.away_wins
+    }
+
+    /// The number of simulated games ending in a tie
+    pub fn ties(&self) -> usize {
+        self.ties
+    }
+
+    /// The mean final score of the home team
+    pub fn mean_home_score(&self) -> f64 {
+        self.home_scores.clone().mean()
+    }
+
+    /// The mean final score of the away team
+    pub fn mean_away_score(&self) -> f64 {
+        self.away_scores.clone().mean()
+    }
+
+    /// The mean final margin of the home team
+    pub fn mean_home_margin(&self) -> f64 {
+        self.home_scores.clone().mean() - self.away_scores.clone().mean()
+    }
+}
+
+/// The standard normal quantile for a two-sided confidence level
+pub fn confidence_z(confidence: f64) -> Result<f64, String> {
+    if !(confidence > 0_f64 && confidence < 1_f64) {
+        return Err(format!("Confidence level must be in range (0, 1): {}", confidence));
+    }
+    let normal = Normal::new(0_f64, 1_f64).unwrap();
+    Ok(normal.inverse_cdf((1_f64 + confidence) / 2_f64))
+}
+
+/// The Wilson score interval of a proportion
+///
+/// The interval is pinned to 0 with no successes and to 1 with no failures,
+/// where rounding would otherwise leave it just inside the bound.
+pub fn wilson_interval(successes: usize, trials: usize, z: f64) -> (f64, f64) {
+    let n = trials as f64;
+    let p = successes as f64 / n;
+    let z2 = z.powi(2);
+    let center = (p + z2 / (2_f64 * n)) / (1_f64 + z2 / n);
+    let half_width = z / (1_f64 + z2 / n) * (p * (1_f64 - p) / n + z2 / (4_f64 * n.powi(2))).sqrt();
+    let lower = if successes == 0 { 0_f64 } else { (center - half_width).max(0_f64) };
+    let upper = if successes == trials { 1_f64 } else { (center + half_width).min(1_f64) };
+    (lower, upper)
+}
+
+pub fn game_winprob(args: FbsimGameWinprobArgs, seed: Option<u64>) -> Result<(), String> {
+    // Load the home and away teams from their files
+    let home_team: FootballTeam = match load_team(&args.home) {
+        Ok(team) => team,
+        Err(e) => return Err(format!("Error loading home team: {}", e)),
+    };
+    let away_team: FootballTeam = match load_team(&args.away) {
+        Ok(team) => team,
+        Err(e) => return Err(format!("Error loading away team: {}", e)),
+    };
+
+    // Load the context, number of trials and confidence level
+    let context = load_context(&args.context)?;
+    let trials: usize = args.trials.unwrap_or(5000);
+    let confidence: f64 = args.confidence.unwrap_or(0.95);
+    let z = confidence_z(confidence)?;
+
+    // Simulate the remainder of the game many times
+    let mut rng = new_rng(seed)?;
+    let progress_bar = ProgressBar::new(trials as u64);
+    let win_prob = WinProbability::sim(&home_team, &away_team, &context, trials, &mut rng, &progress_bar)?;
+    progress_bar.finish();
+
+    // Display the win probability of each outcome with its confidence interval
+    let outcomes = [
+        (format!("Home win ({})", home_team.short_name()), win_prob.home_wins()),
+        (format!("Away win ({})", away_team.short_name()), win_prob.away_wins()),
+        (String::from("Tie"), win_prob.ties())
+    ];
+    let mut outcome_lines = format!("Outcome\tProbability\t{}% CI\tCount", confidence * 100_f64);
+    for (outcome, count) in outcomes.iter() {
+        let (low, high) = wilson_interval(*count, trials, z);
+        let outcome_line = format!(
+            "{}\t{:.2}%\t[{:.2}%, {:.2}%]\t{}",
+            outcome, *count as f64 * 100_f64 / trials as f64,
+            low * 100_f64, high * 100_f64, count
+        );
+        outcome_lines = outcome_lines + "\n" + &outcome_line;
+    }
+    println!();
+    println!("{} @ {} ({} trials)", away_team.name(), home_team.name(), trials);
+    println!(
+        "{} {} - {} {}, {}",
+        context.home_team_short(), context.home_score(),
+        context.away_team_short(), context.away_score(),
+        PlayContext::from(&context)
+    );
+    println!();
+    println!("Win probability:");
+    println!("{}", tabulate(&outcome_lines));
+
+    // Display the mean and standard deviation of each team's final score
+    let score_lines = format!(
+        "Team\tMean Score\tStd Score\nHome ({})\t{:.4}\t{}\nAway ({})\t{:.4}\t{}",
+        home_team.short_name(), win_prob.home_scores.clone().mean(), format_std_dev(&win_prob.home_scores),
+        away_team.short_name(), win_prob.away_scores.clone().mean(), format_std_dev(&win_prob.away_scores)
+    );
+    println!();
+    println!("Expected final score:");
+    println!("{}", tabulate(&score_lines));
+
+    // Display the most common final scores
+    let mut final_scores: Vec<(&(u32, u32), &u32)> = win_prob.final_score_freq.iter().collect();
+    final_scores.sort_by(|a, b| b.1.cmp(a.1).then(a.0.cmp(b.0)));
+    let mut final_score_lines = String::from("Home Score\tAway Score\tFrequency\tCount");
+    for ((home_score, away_score), count) in final_scores.into_iter().take(NUM_COMMON_SCORES) {
+        let freq = *count as f64 / trials as f64;
+        let final_score_line = format!("{}\t{}\t{:.4}%\t{}", home_score, away_score, freq * 100_f64, count);
+        final_score_lines = final_score_lines + "\n" + &final_score_line;
+    }
+    println!();
+    println!("Final score distribution:");
+    println!("{}", tabulate(&final_score_lines));
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const Z: f64 = 1.96;
+
+    #[test]
+    fn test_wilson_interval_no_successes() {
+        let (lower, upper) = wilson_interval(0, 100, Z);
+        assert_eq!(lower, 0_f64);
+        assert!((upper - Z.powi(2) / (100_f64 + Z.powi(2))).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_wilson_interval_all_successes() {
+        let (lower, upper) = wilson_interval(100, 100, Z);
+        assert!((lower - 100_f64 / (100_f64 + Z.powi(2))).abs() < 1e-12);
+        assert_eq!(upper, 1_f64);
+    }
+
+    #[test]
+    fn test_wilson_interval_symmetric() {
+        let (lower, upper) = wilson_interval(50, 100, Z);
+        assert!(lower < 0.5 && upper > 0.5);
+        assert!((0.5 - lower - (upper - 0.5)).abs() < 1e-12);
+        let (low_lower, low_upper) = wilson_interval(30, 100, Z);
+        let (high_lower, high_upper) = wilson_interval(70, 100, Z);
+        assert!((low_lower - (1_f64 - high_upper)).abs() < 1e-12);
+        assert!((low_upper - (1_f64 - high_lower)).abs() < 1e-12);
+    }
+}
@@ -1,3 +1,7 @@
+pub mod convert;
 pub mod create;
+pub mod journal;
+pub mod lock;
+pub mod store;
 pub mod team;
-pub mod season;
\ No newline at end of file
+pub mod season;
@@ -0,0 +1,31 @@
+use std::fs;
+
+use crate::cli::league::FbsimLeagueConvertArgs;
+use crate::league::lock::{lock_league, LockMode};
+use crate::league::store::{open_league, parse_store, write_league, LeagueScope, LeagueStore};
+
+pub fn convert_league(args: FbsimLeagueConvertArgs, wait: bool) -> Result<(), String> {
+    // Refuse to overwrite the league being converted
+    let same_file = match (fs::canonicalize(&args.league), fs::canonicalize(&args.output_file)) {
+        (Ok(a), Ok(b)) => a == b,
+        _ => false
+    };
+    if same_file {
+        return Err(String::from("Output file must differ from the league file"));
+    }
+
+    // Lock the league and its output file, then load the league and convert
+    // it to the other format by default
+    let _output_lock = lock_league(&args.output_file, LockMode::Exclusive, wait)?;
+    let (_lock, league) = open_league(&args.league, &LeagueScope::All, LockMode::Shared, wait)?;
+    let default_store = match LeagueStore::detect(&args.league) {
+        LeagueStore::Json => LeagueStore::Sqlite,
+        LeagueStore::Sqlite => LeagueStore::Json
+    };
+    let store = parse_store(&args.store, default_store)?;
+
+    // Write the league to its output file
+    write_league(&args.output_file, &league, store)?;
+    println!("League converted to {} at {}", store, args.output_file);
+    Ok(())
+}
@@ -1,27 +1,17 @@
-use std::fs;
-
 use fbsim_core::league::League;
 
 use crate::cli::league::FbsimLeagueCreateArgs;
+use crate::league::lock::{lock_league, LockMode};
+use crate::league::store::{parse_store, write_league, LeagueStore};
 
-use serde_json;
-
-pub fn create_league(args: FbsimLeagueCreateArgs) -> Result<(), String> {
+pub fn create_league(args: FbsimLeagueCreateArgs, wait: bool) -> Result<(), String> {
     // Instantiate a new league
     let league = League::new();
 
-    // Serialize the league as stringified JSON
-    let league_str_res = serde_json::to_string_pretty(&league);
-    let league_str = match league_str_res {
-        Ok(league_str) => league_str,
-        Err(error) => return Err(format!("Error serializing league: {}", error))
-    };
-
-    // Write the league to its output file
-    let write_res = fs::write(&args.output_file, league_str);
-    if let Err(e) = write_res {
-        return Err(format!("Error writing league file: {}", e));
-    }
+    // Lock the output file and write the league to it in the given format
+    let _lock = lock_league(&args.output_file, LockMode::Exclusive, wait)?;
+    let store = parse_store(&args.store, LeagueStore::Json)?;
+    write_league(&args.output_file, &league, store)?;
 
     println!("League created at {}", args.output_file);
     Ok(())
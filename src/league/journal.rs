@@ -0,0 +1,279 @@
+use std::env;
+use std::fs;
+use std::io::{Write, stdout};
+use std::path::Path;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::cli::league::{FbsimLeagueHistoryArgs, FbsimLeagueUndoArgs};
+use crate::league::lock::{lock_league, LockMode};
+use crate::league::store::{restore_league_rows, write_atomic, LeagueRowsPatch};
+
+use serde::{Deserialize, Serialize};
+use serde_json;
+use tabwriter::TabWriter;
+
+/// The number of snapshots kept in a league's journal
+const JOURNAL_SIZE: usize = 20;
+
+/// A snapshot of a league taken before a command changed it
+///
+/// JSON leagues are snapshotted as a copy of their file, and SQLite leagues
+/// as the rows the command changed.
+#[derive(Serialize, Deserialize)]
+pub struct JournalEntry {
+    id: usize,
+    timestamp: u64,
+    command: String,
+    #[serde(default)]
+    group: Option<String>,
+    #[serde(default)]
+    rows: bool
+}
+
+/// The directory holding the journal of a league, next to its file
+fn journal_dir(league: &str) -> String {
+    format!("{}.history", league)
+}
+
+/// The file listing the snapshots in the journal of a league
+fn journal_index(league: &str) -> String {
+    format!("{}/journal.json", journal_dir(league))
+}
+
+/// The file holding a snapshot in the journal of a league
+fn snapshot_file(league: &str, id: usize) -> String {
+    format!("{}/{}.snapshot", journal_dir(league), id)
+}
+
+/// Format seconds since the Unix epoch as a UTC date and time
+fn format_timestamp(timestamp: u64) -> String {
+    // Convert days since the epoch to a civil date
+    let days = (timestamp / 86400) as i64 + 719468;
+    let era = days.div_euclid(146097);
+    let day_of_era = days.rem_euclid(146097);
+    let year_of_era = (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let mp = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = year_of_era + era * 400 + (month <= 2) as i64;
+    let seconds = timestamp % 86400;
+    format!(
+        "{}-{:02}-{:02} {:02}:{:02}:{:02} UTC",
+        year, month, day, seconds / 3600, seconds % 3600 / 60, seconds % 60
+    )
+}
+
+/// Read the snapshots in the journal of a league, oldest first
+fn read_journal(league: &str) -> Result<Vec<JournalEntry>, String> {
+    let index = journal_index(league);
+    if !Path::new(&index).exists() {
+        return Ok(Vec::new());
+    }
+    let file = match fs::read_to_string(&index) {
+        Ok(file) => file,
+        Err(e) => return Err(format!("Error loading league history: {}", e)),
+    };
+    match serde_json::from_str(&file) {
+        Ok(entries) => Ok(entries),
+        Err(e) => Err(format!("Error loading league history: {}", e)),
+    }
+}
+
+/// Write the snapshots in the journal of a league
+fn write_journal(league: &str, entries: &[JournalEntry]) -> Result<(), String> {
+    let journal_str = match serde_json::to_string_pretty(entries) {
+        Ok(s) => s,
+        Err(e) => return Err(format!("Error serializing league history: {}", e)),
+    };
+    if let Err(e) = write_atomic(&journal_index(league), journal_str.as_bytes()) {
+        return Err(format!("Error writing league history: {}", e));
+    }
+    Ok(())
+}
+
+/// Read the changed rows held in a snapshot of a SQLite league
+fn read_patch(file: &str) -> Result<LeagueRowsPatch, String> {
+    let contents = match fs::read_to_string(file) {
+        Ok(contents) => contents,
+        Err(e) => return Err(format!("Error loading league snapshot: {}", e)),
+    };
+    match serde_json::from_str(&contents) {
+        Ok(patch) => Ok(patch),
+        Err(e) => Err(format!("Error loading league snapshot: {}", e)),
+    }
+}
+
+/// Write the changed rows of a SQLite league to a snapshot
+fn write_patch(file: &str, patch: &LeagueRowsPatch) -> Result<(), String> {
+    let patch_str = match serde_json::to_string(patch) {
+        Ok(s) => s,
+        Err(e) => return Err(format!("Error serializing league snapshot: {}", e)),
+    };
+    if let Err(e) = write_atomic(file, patch_str.as_bytes()) {
+        return Err(format!("Error writing league snapshot: {}", e));
+    }
+    Ok(())
+}
+
+/// Snapshot a league before it is changed, dropping the oldest snapshots
+/// beyond the size of the journal
+///
+/// A JSON league is snapshotted by copying its file, and a SQLite league by
+/// the given changed rows. If the latest snapshot is in the same group, the
+/// change is folded into it instead of taking a new snapshot, so undoing it
+/// rewinds the whole group.
+pub fn record_snapshot(league: &str, group: Option<&str>, patch: Option<LeagueRowsPatch>) -> Result<(), String> {
+    if !Path::new(league).exists() {
+        return Ok(());
+    }
+    let mut entries = read_journal(league)?;
+    if let (Some(group), Some(last)) = (group, entries.last()) {
+        if last.group.as_deref() == Some(group) && last.rows == patch.is_some() {
+            if let Some(patch) = patch {
+                let file = snapshot_file(league, last.id);
+                let mut earlier = read_patch(&file)?;
+                earlier.merge(patch);
+                write_patch(&file, &earlier)?;
+            }
+            return Ok(());
+        }
+    }
+    let id = entries.last().map(|e| e.id + 1).unwrap_or_default();
+    if let Err(e) = fs::create_dir_all(journal_dir(league)) {
+        return Err(format!("Error writing league history: {}", e));
+    }
+    let rows = patch.is_some();
+    match patch {
+        Some(patch) => write_patch(&snapshot_file(league, id), &patch)?,
+        None => if let Err(e) = fs::copy(league, snapshot_file(league, id)) {
+            return Err(format!("Error writing league snapshot: {}", e));
+        }
+    }
+    let args: Vec<String> = env::args().skip(1).collect();
+    entries.push(
+        JournalEntry{
+            id,
+            timestamp: SystemTime::now().duration_since(UNIX_EPOCH).map(|d| d.as_secs()).unwrap_or_default(),
+            command: format!("fbsim {}", args.join(" ")),
+            group: group.map(String::from),
+            rows
+        }
+    );
+    while entries.len() > JOURNAL_SIZE {
+        let oldest = entries.remove(0);
+        let _ = fs::remove_file(snapshot_file(league, oldest.id));
+    }
+    write_journal(league, &entries)
+}
+
+pub fn undo_league(args: FbsimLeagueUndoArgs, wait: bool) -> Result<(), String> {
+    // Lock the league and take the latest snapshot from its journal
+    let _lock = lock_league(&args.league, LockMode::Exclusive, wait)?;
+    let mut entries = read_journal(&args.league)?;
+    let entry = match entries.pop() {
+        Some(entry) => entry,
+        None => return Err(String::from("No snapshots found in league history")),
+    };
+
+    // Restore the league file or its changed rows from the snapshot
+    let snapshot = snapshot_file(&args.league, entry.id);
+    if entry.rows {
+        restore_league_rows(&args.league, &read_patch(&snapshot)?)?;
+    } else {
+        let contents = match fs::read(&snapshot) {
+            Ok(contents) => contents,
+            Err(e) => return Err(format!("Error loading league snapshot: {}", e)),
+        };
+        if let Err(e) = write_atomic(&args.league, &contents) {
+            return Err(format!("Error writing league file: {}", e));
+        }
+    }
+
+    // Drop the snapshot from the journal
+    write_journal(&args.league, &entries)?;
+    let _ = fs::remove_file(&snapshot);
+    println!("Undid {} from {}", entry.command, format_timestamp(entry.timestamp));
+    Ok(())
+}
+
+pub fn league_history(args: FbsimLeagueHistoryArgs, wait: bool) -> Result<(), String> {
+    // Lock the league and display the snapshots in its journal, newest first
+    let _lock = lock_league(&args.league, LockMode::Shared, wait)?;
+    let entries = read_journal(&args.league)?;
+    let mut tw = TabWriter::new(stdout());
+    writeln!(&mut tw, "ID\tTime\tCommand").map_err(|e| e.to_string())?;
+    for entry in entries.iter().rev() {
+        writeln!(
+            &mut tw, "{}\t{}\t{}",
+            entry.id, format_timestamp(entry.timestamp), entry.command
+        ).map_err(|e| e.to_string())?;
+    }
+    tw.flush().map_err(|e| e.to_string())?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use fbsim_core::league::League;
+
+    use crate::league::store::{open_league, save_league_in_group, write_league, LeagueScope, LeagueStore};
+
+    /// Load a whole league from its file
+    fn load(path: &str) -> League {
+        open_league(path, &LeagueScope::All, LockMode::Shared, true).unwrap().1
+    }
+
+    /// Snapshot a league in the given format before two changes in the same
+    /// group, then check undoing the group restores the original league
+    fn check_undo(file_name: &str, store: LeagueStore) {
+        let path = std::env::temp_dir().join(format!("{}-{}", std::process::id(), file_name));
+        let path = path.to_str().unwrap().to_string();
+        let _ = fs::remove_dir_all(journal_dir(&path));
+        let mut league = League::new();
+        for _ in 0..4 {
+            league.add_team();
+        }
+        league.add_season().unwrap();
+        write_league(&path, &league, store).unwrap();
+
+        // Change the league twice, sharing one snapshot
+        let mut changed = league.clone();
+        for _ in 0..2 {
+            changed.add_team();
+            save_league_in_group(&path, &changed, &LeagueScope::All, Some("test")).unwrap();
+        }
+        assert_eq!(load(&path), changed);
+        let entries = read_journal(&path).unwrap();
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].rows, store == LeagueStore::Sqlite);
+
+        // Undo both changes
+        undo_league(FbsimLeagueUndoArgs{ league: path.clone() }, true).unwrap();
+        assert_eq!(load(&path), league);
+        assert!(read_journal(&path).unwrap().is_empty());
+        let _ = fs::remove_file(&path);
+        let _ = fs::remove_file(format!("{}.lock", path));
+        let _ = fs::remove_dir_all(journal_dir(&path));
+    }
+
+    #[test]
+    fn test_undo_json() {
+        check_undo("fbsim-journal.json", LeagueStore::Json);
+    }
+
+    #[test]
+    fn test_undo_sqlite() {
+        check_undo("fbsim-journal.db", LeagueStore::Sqlite);
+    }
+
+    #[test]
+    fn test_format_timestamp() {
+        assert_eq!(format_timestamp(0), "1970-01-01 00:00:00 UTC");
+        assert_eq!(format_timestamp(951827696), "2000-02-29 12:34:56 UTC");
+        assert_eq!(format_timestamp(4107542399), "2100-02-28 23:59:59 UTC");
+        assert_eq!(format_timestamp(4107542400), "2100-03-01 00:00:00 UTC");
+    }
+}
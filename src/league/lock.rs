@@ -0,0 +1,50 @@
+use std::fs::{File, OpenOptions, TryLockError};
+
+/// How a command locks a league, shared for reading or exclusive for
+/// changing it
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum LockMode {
+    Shared,
+    Exclusive
+}
+
+/// An advisory lock on a league, released when dropped
+pub struct LeagueLock {
+    _file: File
+}
+
+/// The lock file of a league, next to its file
+fn lock_file(league: &str) -> String {
+    format!("{}.lock", league)
+}
+
+/// Lock a league for the rest of a command, waiting for other commands to
+/// release it or failing immediately if not waiting
+pub fn lock_league(league: &str, mode: LockMode, wait: bool) -> Result<LeagueLock, String> {
+    let file = match OpenOptions::new().create(true).truncate(false).write(true).open(lock_file(league)) {
+        Ok(file) => file,
+        Err(e) => return Err(format!("Error opening league lock file: {}", e)),
+    };
+    let try_res = match mode {
+        LockMode::Shared => file.try_lock_shared(),
+        LockMode::Exclusive => file.try_lock()
+    };
+    match try_res {
+        Ok(_) => (),
+        Err(TryLockError::WouldBlock) if wait => {
+            eprintln!("Waiting for another command to release the league {}", league);
+            let lock_res = match mode {
+                LockMode::Shared => file.lock_shared(),
+                LockMode::Exclusive => file.lock()
+            };
+            if let Err(e) = lock_res {
+                return Err(format!("Error locking league: {}", e));
+            }
+        },
+        Err(TryLockError::WouldBlock) => {
+            return Err(format!("League {} is locked by another command", league));
+        },
+        Err(TryLockError::Error(e)) => return Err(format!("Error locking league: {}", e))
+    }
+    Ok(LeagueLock{ _file: file })
+}
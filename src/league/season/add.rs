@@ -1,23 +1,11 @@
-use std::fs;
-
-use fbsim_core::league::League;
-
 use crate::cli::league::season::FbsimLeagueSeasonAddArgs;
+use crate::league::lock::LockMode;
+use crate::league::store::{open_league, save_league, LeagueScope};
 
-use serde_json;
-
-pub fn add_season(args: FbsimLeagueSeasonAddArgs) -> Result<(), String> {
-    // Load the league from its file as mutable
-    let file_res = &fs::read_to_string(&args.league);
-    let file = match file_res {
-        Ok(file) => file,
-        Err(error) => return Err(format!("Error loading league file: {}", error)),
-    };
-    let league_res = serde_json::from_str(file);
-    let mut league: League = match league_res {
-        Ok(league) => league,
-        Err(error) => return Err(format!("Error loading league from file: {}", error)),
-    };
+pub fn add_season(args: FbsimLeagueSeasonAddArgs, wait: bool) -> Result<(), String> {
+    // Lock the league and load it from its file as mutable
+    let scope = LeagueScope::All;
+    let (_lock, mut league) = open_league(&args.league, &scope, LockMode::Exclusive, wait)?;
 
     // Add a new season to the league
     let season_res = league.add_season();
@@ -31,18 +19,8 @@ pub fn add_season(args: FbsimLeagueSeasonAddArgs) -> Result<(), String> {
         None => 0,
     };
 
-    // Serialize the league as JSON
-    let league_str_res = serde_json::to_string_pretty(&league);
-    let league_str: String = match league_str_res {
-        Ok(league_str) => league_str,
-        Err(error) => return Err(format!("Error serializing league: {}", error)),
-    };
-
     // Write the league back to its file
-    let write_res = fs::write(&args.league, league_str);
-    if let Err(e) = write_res {
-        return Err(format!("Error writing league file: {}", e));
-    }
+    save_league(&args.league, &league, &scope)?;
 
     println!("Season {} added to league", year);
     Ok(())
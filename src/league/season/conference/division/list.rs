@@ -1,25 +1,15 @@
-use std::fs;
 use std::io::{Write, stdout};
 
-use fbsim_core::league::League;
-
 use crate::cli::league::season::conference::division::FbsimLeagueSeasonConferenceDivisionListArgs;
+use crate::league::lock::LockMode;
+use crate::league::store::{open_league, LeagueScope};
 
-use serde_json;
 use tabwriter::TabWriter;
 
-pub fn list_divisions(args: FbsimLeagueSeasonConferenceDivisionListArgs) -> Result<(), String> {
-    // Load the league from its file
-    let file_res = &fs::read_to_string(&args.league);
-    let file = match file_res {
-        Ok(file) => file,
-        Err(error) => return Err(format!("Error loading league file: {}", error)),
-    };
-    let league_res = serde_json::from_str(file);
-    let league: League = match league_res {
-        Ok(league) => league,
-        Err(error) => return Err(format!("Error loading league from file: {}", error)),
-    };
+pub fn list_divisions(args: FbsimLeagueSeasonConferenceDivisionListArgs, wait: bool) -> Result<(), String> {
+    // Lock the league and load it from its file
+    let scope = LeagueScope::Season(Some(args.year));
+    let (_lock, league) = open_league(&args.league, &scope, LockMode::Shared, wait)?;
 
     // Get the season, conference, and divisions
     let season = match league.season(args.year) {
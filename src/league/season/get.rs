@@ -1,27 +1,18 @@
 use std::collections::HashMap;
-use std::fs;
 use std::io::{Write, stdout};
 
-use fbsim_core::league::League;
 use fbsim_core::league::season::LeagueSeason;
 
 use crate::cli::league::season::FbsimLeagueSeasonGetArgs;
+use crate::league::lock::LockMode;
+use crate::league::store::{open_league, LeagueScope};
 
-use serde_json;
 use tabwriter::TabWriter;
 
-pub fn get_season(args: FbsimLeagueSeasonGetArgs) -> Result<(), String> {
-    // Load the league from its file
-    let file_res = &fs::read_to_string(&args.league);
-    let file = match file_res {
-        Ok(file) => file,
-        Err(error) => return Err(format!("Error loading league file: {}", error)),
-    };
-    let league_res = serde_json::from_str(file);
-    let league: League = match league_res {
-        Ok(league) => league,
-        Err(error) => return Err(format!("Error loading league from file: {}", error)),
-    };
+pub fn get_season(args: FbsimLeagueSeasonGetArgs, wait: bool) -> Result<(), String> {
+    // Lock the league and load it from its file
+    let scope = LeagueScope::Season(Some(args.year));
+    let (_lock, league) = open_league(&args.league, &scope, LockMode::Shared, wait)?;
 
     // Get a season from the league
     let season: &LeagueSeason = match league.season(args.year) {
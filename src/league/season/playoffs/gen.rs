@@ -1,24 +1,14 @@
-use std::fs;
-
-use fbsim_core::league::League;
 use fbsim_core::league::season::LeagueSeasonPlayoffOptions;
 
 use crate::cli::league::season::playoffs::FbsimLeagueSeasonPlayoffsGenArgs;
+use crate::rng::new_rng;
+use crate::league::lock::LockMode;
+use crate::league::store::{open_league, save_league, LeagueScope};
 
-use serde_json;
-
-pub fn gen_playoffs(args: FbsimLeagueSeasonPlayoffsGenArgs) -> Result<(), String> {
-    // Load the league from its file as mutable
-    let file_res = &fs::read_to_string(&args.league);
-    let file = match file_res {
-        Ok(file) => file,
-        Err(error) => return Err(format!("Error loading league file: {}", error)),
-    };
-    let league_res = serde_json::from_str(file);
-    let mut league: League = match league_res {
-        Ok(league) => league,
-        Err(error) => return Err(format!("Error loading league from file: {}", error)),
-    };
+pub fn gen_playoffs(args: FbsimLeagueSeasonPlayoffsGenArgs, seed: Option<u64>, wait: bool) -> Result<(), String> {
+    // Lock the league and load it from its file as mutable
+    let scope = LeagueScope::Season(None);
+    let (_lock, mut league) = open_league(&args.league, &scope, LockMode::Exclusive, wait)?;
 
     // Get the current season
     let season = match league.current_season_mut() {
@@ -27,7 +17,7 @@ pub fn gen_playoffs(args: FbsimLeagueSeasonPlayoffsGenArgs) -> Result<(), String
     };
 
     // Generate the playoffs
-    let mut rng = rand::thread_rng();
+    let mut rng = new_rng(seed)?;
     let mut options = LeagueSeasonPlayoffOptions::new();
     let result_msg = if args.per_conference {
         // Validate conferences exist
@@ -59,18 +49,8 @@ pub fn gen_playoffs(args: FbsimLeagueSeasonPlayoffsGenArgs) -> Result<(), String
         format!("Playoffs generated with {} teams", args.num_teams)
     };
 
-    // Serialize the league as JSON
-    let league_res = serde_json::to_string_pretty(&league);
-    let league_str: String = match league_res {
-        Ok(league_str) => league_str,
-        Err(error) => return Err(format!("Error serializing league: {}", error)),
-    };
-
     // Write the league back to its file
-    let write_res = fs::write(&args.league, league_str);
-    if let Err(e) = write_res {
-        return Err(format!("Error writing league file: {}", e));
-    }
+    save_league(&args.league, &league, &scope)?;
     println!("{}", result_msg);
     Ok(())
 }
@@ -1,27 +1,21 @@
-use std::fs;
 use std::io::{Write, stdout};
 
-use fbsim_core::league::League;
 use fbsim_core::league::season::LeagueSeason;
 use fbsim_core::league::season::playoffs::picture::{PlayoffPicture, PlayoffPictureOptions, PlayoffStatus};
 
 use crate::cli::league::season::playoffs::FbsimLeagueSeasonPlayoffsPictureArgs;
+use crate::league::lock::LockMode;
+use crate::league::store::{open_league, LeagueScope};
 
-use serde_json;
 use tabwriter::TabWriter;
 
-pub fn get_playoffs_picture(args: FbsimLeagueSeasonPlayoffsPictureArgs) -> Result<(), String> {
-    // Load the league from its file
-    let file_res = &fs::read_to_string(&args.league);
-    let file = match file_res {
-        Ok(file) => file,
-        Err(error) => return Err(format!("Error loading league file: {}", error)),
-    };
-    let league_res = serde_json::from_str(file);
-    let league: League = match league_res {
-        Ok(league) => league,
-        Err(error) => return Err(format!("Error loading league from file: {}", error)),
-    };
+/// The number of playoff teams in the playoff picture when none is given
+pub const DEFAULT_NUM_PLAYOFF_TEAMS: usize = 4;
+
+pub fn get_playoffs_picture(args: FbsimLeagueSeasonPlayoffsPictureArgs, wait: bool) -> Result<(), String> {
+    // Lock the league and load it from its file
+    let scope = LeagueScope::Season(Some(args.year));
+    let (_lock, league) = open_league(&args.league, &scope, LockMode::Shared, wait)?;
 
     // Get the league season
     let season = match league.season(args.year) {
@@ -45,6 +39,7 @@ pub fn get_playoffs_picture(args: FbsimLeagueSeasonPlayoffsPictureArgs) -> Resul
         ).count();
 
     // Determine if we should use conference-based playoff picture
+    let num_playoff_teams = args.num_playoff_teams.unwrap_or(DEFAULT_NUM_PLAYOFF_TEAMS);
     let has_conferences = !season.conferences().is_empty();
     if args.per_conference {
         if !has_conferences {
@@ -53,14 +48,14 @@ pub fn get_playoffs_picture(args: FbsimLeagueSeasonPlayoffsPictureArgs) -> Resul
                 Use 'league season conference add' first."
             ));
         }
-        display_conference_playoff_picture(season, &args, weeks_remaining)?;
+        display_conference_playoff_picture(season, &args, num_playoff_teams, weeks_remaining)?;
     } else if has_conferences && args.conference.is_some() {
         // Display single conference
         let conf_index = args.conference.unwrap();
-        display_single_conference_picture(season, conf_index, &args, weeks_remaining)?;
+        display_single_conference_picture(season, conf_index, &args, num_playoff_teams, weeks_remaining)?;
     } else {
         // Display traditional playoff picture
-        display_traditional_playoff_picture(season, &args, weeks_remaining)?;
+        display_traditional_playoff_picture(season, &args, num_playoff_teams, weeks_remaining)?;
     }
     Ok(())
 }
@@ -68,6 +63,7 @@ pub fn get_playoffs_picture(args: FbsimLeagueSeasonPlayoffsPictureArgs) -> Resul
 fn display_traditional_playoff_picture(
     season: &LeagueSeason,
     args: &FbsimLeagueSeasonPlayoffsPictureArgs,
+    num_playoff_teams: usize,
     weeks_remaining: usize
 ) -> Result<(), String> {
     // Get the playoff picture (explicitly non-conference)
@@ -75,11 +71,11 @@ fn display_traditional_playoff_picture(
         by_conference: Some(false),
         division_winners_guaranteed: args.division_winners,
     };
-    let picture = PlayoffPicture::from_season(season, args.num_playoff_teams, Some(options))?;
+    let picture = PlayoffPicture::from_season(season, num_playoff_teams, Some(options))?;
 
     // Display playoff picture
     println!("Playoff Picture for {} Season", args.year);
-    println!("Top {} teams make the playoffs", args.num_playoff_teams);
+    println!("Top {} teams make the playoffs", num_playoff_teams);
     println!("Weeks remaining in season: {}", weeks_remaining);
     println!();
     display_playoff_picture_sections(&picture)?;
@@ -90,13 +86,14 @@ fn display_traditional_playoff_picture(
 fn display_conference_playoff_picture(
     season: &LeagueSeason,
     args: &FbsimLeagueSeasonPlayoffsPictureArgs,
+    num_playoff_teams: usize,
     weeks_remaining: usize
 ) -> Result<(), String> {
     let conferences = season.conferences();
 
     // Display header
     println!("Playoff Picture for {} Season", args.year);
-    println!("{} teams per conference make the playoffs", args.num_playoff_teams);
+    println!("{} teams per conference make the playoffs", num_playoff_teams);
     println!("Weeks remaining in season: {}", weeks_remaining);
     println!();
 
@@ -107,7 +104,7 @@ fn display_conference_playoff_picture(
     };
     let picture = PlayoffPicture::from_season(
         season,
-        args.num_playoff_teams,
+        num_playoff_teams,
         Some(options)
     )?;
     for (conf_index, conference) in conferences.iter().enumerate() {
@@ -218,6 +215,7 @@ fn display_single_conference_picture(
     season: &LeagueSeason,
     conf_index: usize,
     args: &FbsimLeagueSeasonPlayoffsPictureArgs,
+    num_playoff_teams: usize,
     weeks_remaining: usize
 ) -> Result<(), String> {
     let conferences = season.conferences();
@@ -228,7 +226,7 @@ fn display_single_conference_picture(
 
     // Display header
     println!("{} Playoff Picture for {} Season", conference.name(), args.year);
-    println!("Top {} teams make the playoffs", args.num_playoff_teams);
+    println!("Top {} teams make the playoffs", num_playoff_teams);
     println!("Weeks remaining in season: {}", weeks_remaining);
     println!();
 
@@ -237,7 +235,7 @@ fn display_single_conference_picture(
         by_conference: Some(false),
         division_winners_guaranteed: args.division_winners,
     };
-    let picture = PlayoffPicture::from_season(season, args.num_playoff_teams, Some(options))?;
+    let picture = PlayoffPicture::from_season(season, num_playoff_teams, Some(options))?;
     display_playoff_picture_sections(&picture)?;
     display_legend();
     Ok(())
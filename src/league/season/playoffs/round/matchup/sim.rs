@@ -1,38 +1,34 @@
-use std::fs;
 use std::io::{stdout, Write};
 use std::{thread, time};
 
 use crossterm::{cursor, terminal, QueueableCommand};
 
-use fbsim_core::league::League;
 use fbsim_core::league::season::matchup::LeagueSeasonMatchup;
 use fbsim_core::game::play::{Drive, Game};
 use fbsim_core::game::play::result::{PlayResult, PlayTypeResult};
 
 use crate::cli::league::season::playoffs::round::matchup::FbsimLeagueSeasonPlayoffsRoundMatchupSimArgs;
-
-use serde_json;
-
-pub fn sim_playoffs_matchup(args: FbsimLeagueSeasonPlayoffsRoundMatchupSimArgs) -> Result<(), String> {
-    // Load the league from its file as mutable
-    let file_res = &fs::read_to_string(&args.league);
-    let file = match file_res {
-        Ok(file) => file,
-        Err(error) => return Err(format!("Error loading league file: {}", error)),
-    };
-    let league_res = serde_json::from_str(file);
-    let mut league: League = match league_res {
-        Ok(league) => league,
-        Err(error) => return Err(format!("Error loading league from file: {}", error)),
-    };
+use crate::game::drivechart::drive_chart;
+use crate::game::pbp::write_pbp;
+use crate::game::playback::display_game_over;
+use crate::rng::new_rng;
+use crate::league::lock::LockMode;
+use crate::league::store::{open_league, save_league, LeagueScope};
+
+pub fn sim_playoffs_matchup(args: FbsimLeagueSeasonPlayoffsRoundMatchupSimArgs, seed: Option<u64>, wait: bool) -> Result<(), String> {
+    // Lock the league and load its current season as mutable, with only the
+    // game of the matchup
+    let conference = if args.winners_bracket { None } else { Some(args.conference) };
+    let scope = LeagueScope::PlayoffMatchup(None, conference, args.round, args.matchup);
+    let (_lock, mut league) = open_league(&args.league, &scope, LockMode::Exclusive, wait)?;
 
     // Load the playback speed argument
     let playback_speed: f64 = args.playback_speed.unwrap_or(2.0);
 
     // Simulate the matchup play-by-play
-    let mut rng = rand::thread_rng();
+    let mut rng = new_rng(seed)?;
     let mut stdout = stdout();
-    loop {
+    let final_game: Option<Game> = loop {
         // Get the current season mutably and simulate a play
         let game_opt: Option<Game> = {
             let season = match league.current_season_mut() {
@@ -112,11 +108,11 @@ pub fn sim_playoffs_matchup(args: FbsimLeagueSeasonPlayoffsRoundMatchupSimArgs)
             println!("\n");
         }
 
-        // Break if the game is over
+        // Break with the final game if the game is over
         if matchup.context().game_over() {
-            break
+            break game_opt
         }
-    }
+    };
 
     // Print game-over message and final stats
     let season = match league.current_season() {
@@ -124,28 +120,14 @@ pub fn sim_playoffs_matchup(args: FbsimLeagueSeasonPlayoffsRoundMatchupSimArgs)
         None => return Err(String::from("No current season found after simulating game"))
     };
     let matchup = get_matchup(season.playoffs(), &args)?;
-    let home_stats = match matchup.home_stats() {
-        Some(s) => s,
-        None => return Err(String::from("Failed to get home stats after simulating game"))
+    let game = match final_game {
+        Some(g) => g,
+        None => return Err(String::from("Failed to get game after simulating game"))
     };
-    let away_stats = match matchup.away_stats() {
-        Some(s) => s,
-        None => return Err(String::from("Failed to get away stats after simulating game"))
-    };
-    let context = matchup.context();
-    println!("{} Game over", context);
-    println!();
-    println!(
-        "{} stats\n{}",
-        context.home_team_short(),
-        home_stats
-    );
-    println!();
-    println!(
-        "{} stats\n{}",
-        context.away_team_short(),
-        away_stats
-    );
+    if args.drive_chart {
+        println!("{}", drive_chart(&game));
+    }
+    display_game_over(matchup.context(), &game);
 
     // Try to generate the next round if playoffs are not yet complete
     if !season.playoffs().complete() {
@@ -156,18 +138,11 @@ pub fn sim_playoffs_matchup(args: FbsimLeagueSeasonPlayoffsRoundMatchupSimArgs)
         season.generate_next_playoff_round(&mut rng)?;
     }
 
-    // Serialize the league as JSON
-    let league_res = serde_json::to_string_pretty(&league);
-    let league_str: String = match league_res {
-        Ok(league_str) => league_str,
-        Err(error) => return Err(format!("Error serializing league: {}", error)),
-    };
-
     // Write the league back to its file
-    let write_res = fs::write(&args.league, league_str);
-    if let Err(e) = write_res {
-        return Err(format!("Error writing league file: {}", e));
-    }
+    save_league(&args.league, &league, &scope)?;
+
+    // If play-by-play export files were given, export the game to them
+    write_pbp(&game, &args.pbp_csv, &args.pbp_ndjson)?;
     Ok(())
 }
 
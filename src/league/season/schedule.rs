@@ -1,25 +1,14 @@
-use std::fs;
-
-use fbsim_core::league::League;
 use fbsim_core::league::season::LeagueSeasonScheduleOptions;
 
 use crate::cli::league::season::schedule::FbsimLeagueSeasonScheduleGenArgs;
+use crate::rng::new_rng;
+use crate::league::lock::LockMode;
+use crate::league::store::{open_league, save_league, LeagueScope};
 
-use rand::{SeedableRng};
-use rand::rngs::StdRng;
-use serde_json;
-
-pub fn generate_schedule(args: FbsimLeagueSeasonScheduleGenArgs) -> Result<(), String> {
-    // Load the league from its file
-    let file_res = &fs::read_to_string(&args.league);
-    let file = match file_res {
-        Ok(file) => file,
-        Err(error) => return Err(format!("Error loading league file: {}", error)),
-    };
-    let mut league: League = match serde_json::from_str(file) {
-        Ok(league) => league,
-        Err(error) => return Err(format!("Error loading league from file: {}", error)),
-    };
+pub fn generate_schedule(args: FbsimLeagueSeasonScheduleGenArgs, seed: Option<u64>, wait: bool) -> Result<(), String> {
+    // Lock the league and load it from its file
+    let scope = LeagueScope::Season(None);
+    let (_lock, mut league) = open_league(&args.league, &scope, LockMode::Exclusive, wait)?;
 
     // Validate conference-based options
     let has_conference_options = args.division_games.is_some()
@@ -49,14 +38,17 @@ pub fn generate_schedule(args: FbsimLeagueSeasonScheduleGenArgs) -> Result<(), S
         cross_conference_games: args.cross_conference_games,
     };
 
-    // Attempt to generate a schedule for the league
-    let mut rng = match args.seed {
-        Some(seed) => StdRng::seed_from_u64(seed),
-        None => match StdRng::from_rng(rand::thread_rng()) {
-            Ok(rng) => rng,
-            Err(error) => return Err(format!("Failed to instantiate rng: {}", error)),
+    // Fall back to the deprecated -s seed if the global seed is not given
+    let seed = match args.seed {
+        Some(s) => {
+            eprintln!("Warning: -s is deprecated for schedule gen, use --seed instead");
+            seed.or(Some(s))
         },
+        None => seed
     };
+
+    // Attempt to generate a schedule for the league
+    let mut rng = new_rng(seed)?;
     if let Err(e) = league.generate_schedule(options, &mut rng) {
         return Err(format!("Error generating league schedule: {}", e));
     }
@@ -67,17 +59,8 @@ pub fn generate_schedule(args: FbsimLeagueSeasonScheduleGenArgs) -> Result<(), S
         None => 0,
     };
 
-    // Serialize the league as JSON
-    let league_str: String = match serde_json::to_string_pretty(&league) {
-        Ok(league_str) => league_str,
-        Err(error) => return Err(format!("Error serializing league: {}", error)),
-    };
-
     // Write the league back to its file
-    let write_res = fs::write(&args.league, league_str);
-    if let Err(e) = write_res {
-        return Err(format!("Error writing league file: {}", e));
-    };
+    save_league(&args.league, &league, &scope)?;
 
     println!("Schedule generated with {} weeks", num_weeks);
     Ok(())
@@ -1,25 +1,16 @@
-use std::fs;
 use std::io::{Write, stdout};
 
-use fbsim_core::league::League;
-
 use crate::cli::league::season::FbsimLeagueSeasonSimArgs;
+use crate::rng::new_rng;
+use crate::league::lock::LockMode;
+use crate::league::store::{open_league, save_league, LeagueScope};
 
-use serde_json;
 use tabwriter::TabWriter;
 
-pub fn sim_season(args: FbsimLeagueSeasonSimArgs) -> Result<(), String> {
-    // Load the league from its file as mutable
-    let file_res = &fs::read_to_string(&args.league);
-    let file = match file_res {
-        Ok(file) => file,
-        Err(error) => return Err(format!("Error loading league file: {}", error)),
-    };
-    let league_res = serde_json::from_str(file);
-    let mut league: League = match league_res {
-        Ok(league) => league,
-        Err(error) => return Err(format!("Error loading league from file: {}", error)),
-    };
+pub fn sim_season(args: FbsimLeagueSeasonSimArgs, seed: Option<u64>, wait: bool) -> Result<(), String> {
+    // Lock the league and load it from its file as mutable
+    let scope = LeagueScope::Season(None);
+    let (_lock, mut league) = open_league(&args.league, &scope, LockMode::Exclusive, wait)?;
 
     // Validate that the season has teams and a schedule
     let season = match league.current_season() {
@@ -34,7 +25,7 @@ pub fn sim_season(args: FbsimLeagueSeasonSimArgs) -> Result<(), String> {
     }
 
     // Simulate the current league season
-    let mut rng = rand::thread_rng();
+    let mut rng = new_rng(seed)?;
     if let Err(e) = league.sim(&mut rng) {
         return Err(
             format!(
@@ -66,17 +57,7 @@ pub fn sim_season(args: FbsimLeagueSeasonSimArgs) -> Result<(), String> {
     }
     tw.flush().map_err(|e| e.to_string())?;
 
-    // Serialize the league as JSON
-    let league_res = serde_json::to_string_pretty(&league);
-    let league_str: String = match league_res {
-        Ok(league_str) => league_str,
-        Err(error) => return Err(format!("Error serializing league: {}", error)),
-    };
-
     // Write the league back to its file
-    let write_res = fs::write(&args.league, league_str);
-    if let Err(e) = write_res {
-        return Err(format!("Error writing league file: {}", e));
-    }
+    save_league(&args.league, &league, &scope)?;
     Ok(())
 }
@@ -1,31 +1,22 @@
-use std::fs;
 use std::io::{Write, stdout};
 
-use fbsim_core::league::League;
 use fbsim_core::league::season::LeagueSeason;
 
 use crate::cli::league::season::standings::FbsimLeagueSeasonStandingsArgs;
+use crate::league::lock::LockMode;
+use crate::league::store::{open_league, LeagueScope};
 
-use serde_json;
 use tabwriter::TabWriter;
 
-pub fn get_standings(args: FbsimLeagueSeasonStandingsArgs) -> Result<(), String> {
+pub fn get_standings(args: FbsimLeagueSeasonStandingsArgs, wait: bool) -> Result<(), String> {
     // Validate args: division requires conference
     if args.division.is_some() && args.conference.is_none() {
         return Err(String::from("Division filter requires conference filter (-c/--conference)"));
     }
 
-    // Load the league from its file
-    let file_res = &fs::read_to_string(&args.league);
-    let file = match file_res {
-        Ok(file) => file,
-        Err(error) => return Err(format!("Error loading league file: {}", error)),
-    };
-    let league_res = serde_json::from_str(file);
-    let league: League = match league_res {
-        Ok(league) => league,
-        Err(error) => return Err(format!("Error loading league from file: {}", error)),
-    };
+    // Lock the league and load it from its file
+    let scope = LeagueScope::Season(Some(args.year));
+    let (_lock, league) = open_league(&args.league, &scope, LockMode::Shared, wait)?;
 
     // Get the season
     let season = match league.season(args.year) {
@@ -1,27 +1,19 @@
-use std::fs;
 use std::io::{Write, stdout};
 
-use fbsim_core::league::League;
 use fbsim_core::league::matchup::LeagueTeamRecord;
 use fbsim_core::league::season::playoffs::picture::PlayoffStatus;
 
 use crate::cli::league::season::team::FbsimLeagueSeasonTeamGetArgs;
+use crate::league::lock::LockMode;
+use crate::league::season::playoffs::picture::DEFAULT_NUM_PLAYOFF_TEAMS;
+use crate::league::store::{open_league, LeagueScope};
 
-use serde_json;
 use tabwriter::TabWriter;
 
-pub fn get_season_team(args: FbsimLeagueSeasonTeamGetArgs) -> Result<(), String> {
-    // Load the league from its file
-    let file_res = &fs::read_to_string(&args.league);
-    let file = match file_res {
-        Ok(file) => file,
-        Err(error) => return Err(format!("Error loading league file: {}", error)),
-    };
-    let league_res = serde_json::from_str(file);
-    let league: League = match league_res {
-        Ok(league) => league,
-        Err(error) => return Err(format!("Error loading league from file: {}", error)),
-    };
+pub fn get_season_team(args: FbsimLeagueSeasonTeamGetArgs, wait: bool) -> Result<(), String> {
+    // Lock the league and load it from its file
+    let scope = LeagueScope::Season(Some(args.year));
+    let (_lock, league) = open_league(&args.league, &scope, LockMode::Shared, wait)?;
 
     // Get the league season and team
     let season = match league.season(args.year) {
@@ -78,7 +70,7 @@ pub fn get_season_team(args: FbsimLeagueSeasonTeamGetArgs) -> Result<(), String>
 
     // Display playoff picture status during regular season
     if season.started() && !season.regular_season_complete() && !playoffs_started {
-        if let Ok(picture) = season.playoff_picture(args.num_playoff_teams) {
+        if let Ok(picture) = season.playoff_picture(args.num_playoff_teams.unwrap_or(DEFAULT_NUM_PLAYOFF_TEAMS)) {
             if let Some(entry) = picture.team_status(args.id) {
                 let status_str = format_playoff_status(entry.status());
                 writeln!(&mut tw, "Playoff Status:\t{}", status_str).map_err(|e| e.to_string())?;
@@ -1,28 +1,20 @@
 use std::collections::HashMap;
-use std::fs;
 use std::io::{Write, stdout};
 
-use fbsim_core::league::League;
 use fbsim_core::league::season::matchup::LeagueSeasonMatchups;
 use fbsim_core::league::season::playoffs::picture::PlayoffStatus;
 
 use crate::cli::league::season::team::FbsimLeagueSeasonTeamListArgs;
+use crate::league::lock::LockMode;
+use crate::league::season::playoffs::picture::DEFAULT_NUM_PLAYOFF_TEAMS;
+use crate::league::store::{open_league, LeagueScope};
 
-use serde_json;
 use tabwriter::TabWriter;
 
-pub fn list_season_teams(args: FbsimLeagueSeasonTeamListArgs) -> Result<(), String> {
-    // Load the league from its file
-    let file_res = &fs::read_to_string(&args.league);
-    let file = match file_res {
-        Ok(file) => file,
-        Err(error) => return Err(format!("Error loading league file: {}", error)),
-    };
-    let league_res = serde_json::from_str(file);
-    let league: League = match league_res {
-        Ok(league) => league,
-        Err(error) => return Err(format!("Error loading league from file: {}", error)),
-    };
+pub fn list_season_teams(args: FbsimLeagueSeasonTeamListArgs, wait: bool) -> Result<(), String> {
+    // Lock the league and load it from its file
+    let scope = LeagueScope::Season(Some(args.year));
+    let (_lock, league) = open_league(&args.league, &scope, LockMode::Shared, wait)?;
 
     // Get the league season
     let season = match league.season(args.year) {
@@ -37,7 +29,7 @@ pub fn list_season_teams(args: FbsimLeagueSeasonTeamListArgs) -> Result<(), Stri
 
     // Get playoff picture if regular season is in progress (started but not complete, playoffs not started)
     let playoff_picture = if season.started() && !season.regular_season_complete() && !playoffs_started {
-        season.playoff_picture(args.num_playoff_teams).ok()
+        season.playoff_picture(args.num_playoff_teams.unwrap_or(DEFAULT_NUM_PLAYOFF_TEAMS)).ok()
     } else {
         None
     };
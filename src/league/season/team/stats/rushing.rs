@@ -1,27 +1,18 @@
-use std::fs;
 use std::io::{Write, stdout};
 
 use fbsim_core::game::stat::OffensiveStats;
-use fbsim_core::league::League;
 use fbsim_core::league::season::matchup::LeagueSeasonMatchups;
 
 use crate::cli::league::season::team::stats::FbsimLeagueSeasonTeamStatsRushingArgs;
+use crate::league::lock::LockMode;
+use crate::league::store::{open_league, LeagueScope};
 
-use serde_json;
 use tabwriter::TabWriter;
 
-pub fn list_season_rushing(args: FbsimLeagueSeasonTeamStatsRushingArgs) -> Result<(), String> {
-    // Load the league from its file
-    let file_res = &fs::read_to_string(&args.league);
-    let file = match file_res {
-        Ok(file) => file,
-        Err(error) => return Err(format!("Error loading league file: {}", error)),
-    };
-    let league_res = serde_json::from_str(file);
-    let league: League = match league_res {
-        Ok(league) => league,
-        Err(error) => return Err(format!("Error loading league from file: {}", error)),
-    };
+pub fn list_season_rushing(args: FbsimLeagueSeasonTeamStatsRushingArgs, wait: bool) -> Result<(), String> {
+    // Lock the league and load it from its file
+    let scope = LeagueScope::Season(Some(args.year));
+    let (_lock, league) = open_league(&args.league, &scope, LockMode::Shared, wait)?;
 
     // Display the results in a table
     let mut tw = TabWriter::new(stdout());
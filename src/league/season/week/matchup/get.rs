@@ -1,23 +1,13 @@
-use std::fs;
-
-use fbsim_core::league::League;
-
 use crate::cli::league::season::week::matchup::FbsimLeagueSeasonWeekMatchupGetArgs;
+use crate::game::drivechart::drive_chart;
+use crate::game::pbp::write_pbp;
+use crate::league::lock::LockMode;
+use crate::league::store::{open_league, LeagueScope};
 
-use serde_json;
-
-pub fn get_matchup(args: FbsimLeagueSeasonWeekMatchupGetArgs) -> Result<(), String> {
-    // Load the league from its file
-    let file_res = &fs::read_to_string(&args.league);
-    let file = match file_res {
-        Ok(file) => file,
-        Err(error) => return Err(format!("Error loading league file: {}", error)),
-    };
-    let league_res = serde_json::from_str(file);
-    let league: League = match league_res {
-        Ok(league) => league,
-        Err(error) => return Err(format!("Error loading league from file: {}", error)),
-    };
+pub fn get_matchup(args: FbsimLeagueSeasonWeekMatchupGetArgs, wait: bool) -> Result<(), String> {
+    // Lock the league and load the season with only the game of the matchup
+    let scope = LeagueScope::WeekMatchup(Some(args.year), args.week, args.matchup);
+    let (_lock, league) = open_league(&args.league, &scope, LockMode::Shared, wait)?;
 
     // Get the league season matchup
     let season = match league.season(args.year) {
@@ -62,8 +52,12 @@ pub fn get_matchup(args: FbsimLeagueSeasonWeekMatchupGetArgs) -> Result<(), Stri
     } else if context.started() {
         // Display play-by-play log up to this point
         if let Some(game) = matchup.game() {
-            for drive in game.drives().iter() {
-                println!("{}\n", drive);
+            if args.drive_chart {
+                println!("{}", drive_chart(game));
+            } else {
+                for drive in game.drives().iter() {
+                    println!("{}\n", drive);
+                }
             }
         } else {
             println!("{}", context);
@@ -71,5 +65,17 @@ pub fn get_matchup(args: FbsimLeagueSeasonWeekMatchupGetArgs) -> Result<(), Stri
     } else {
         println!("{} Pending", context);
     }
+
+    // Export the play-by-play if requested, both it and the drive chart
+    // need the matchup's game
+    if args.pbp_csv.is_some() || args.pbp_ndjson.is_some() || args.drive_chart {
+        match matchup.game() {
+            Some(game) => write_pbp(game, &args.pbp_csv, &args.pbp_ndjson)?,
+            None if context.game_over() => return Err(
+                String::from("No play-by-play found for matchup, completed matchups only keep their final stats")
+            ),
+            None => return Err(String::from("No play-by-play found for matchup, it has not started"))
+        }
+    }
     Ok(())
 }
@@ -1,42 +1,28 @@
-use std::fs;
-
-use fbsim_core::league::League;
-
 use crate::cli::league::season::week::matchup::play::FbsimLeagueSeasonWeekMatchupPlaySimArgs;
+use crate::rng::new_rng;
+use crate::league::lock::LockMode;
+use crate::league::store::{open_league, save_league_in_group, LeagueScope};
 
-use serde_json;
-
-pub fn sim_play(args: FbsimLeagueSeasonWeekMatchupPlaySimArgs) -> Result<(), String> {
-    // Load the league from its file as mutable
-    let file_res = &fs::read_to_string(&args.league);
-    let file = match file_res {
-        Ok(file) => file,
-        Err(error) => return Err(format!("Error loading league file: {}", error)),
-    };
-    let league_res = serde_json::from_str(file);
-    let mut league: League = match league_res {
-        Ok(league) => league,
-        Err(error) => return Err(format!("Error loading league from file: {}", error)),
-    };
+pub fn sim_play(args: FbsimLeagueSeasonWeekMatchupPlaySimArgs, seed: Option<u64>, wait: bool) -> Result<(), String> {
+    // Lock the league and load its current season as mutable, with only the
+    // game of the matchup
+    let scope = LeagueScope::WeekMatchup(None, args.week, args.matchup);
+    let (_lock, mut league) = open_league(&args.league, &scope, LockMode::Exclusive, wait)?;
 
     // Simulate the matchup
-    let mut rng = rand::thread_rng();
+    let mut rng = new_rng(seed)?;
     let _ = match league.sim_play(args.week, args.matchup, &mut rng) {
         Ok(opt) => opt,
         Err(error) => return Err(format!("Error simulating next play for matchup: {}", error)),
     };
 
-    // Serialize the league as JSON
-    let league_res = serde_json::to_string_pretty(&league);
-    let league_str: String = match league_res {
-        Ok(league_str) => league_str,
-        Err(error) => return Err(format!("Error serializing league: {}", error)),
+    // Write the league back to its file, sharing one snapshot across the
+    // plays of the matchup
+    let year = match league.current_season() {
+        Some(season) => *season.year(),
+        None => return Err(String::from("No current season found after simulating play"))
     };
-
-    // Write the league back to its file
-    let write_res = fs::write(&args.league, league_str);
-    if let Err(e) = write_res {
-        return Err(format!("Error writing league file: {}", e));
-    }
+    let group = format!("play {} {} {}", year, args.week, args.matchup);
+    save_league_in_group(&args.league, &league, &scope, Some(&group))?;
     Ok(())
 }
@@ -1,37 +1,34 @@
-use std::fs;
-use std::io::{stdout, Write};
-use std::{thread, time};
+use std::io::stdout;
 
-use crossterm::{cursor, terminal, QueueableCommand};
-
-use fbsim_core::league::League;
 use fbsim_core::game::play::Game;
-use fbsim_core::game::play::result::{PlayResult, PlayTypeResult};
 
 use crate::cli::league::season::week::matchup::FbsimLeagueSeasonWeekMatchupSimArgs;
+use crate::game::drivechart::drive_chart;
+use crate::game::pbp::write_pbp;
+use crate::game::playback::{display_game_over, playback_drive};
+use crate::game::tui::Scoreboard;
+use crate::rng::new_rng;
+use crate::league::lock::LockMode;
+use crate::league::store::{open_league, save_league, LeagueScope};
 
-use serde_json;
-
-pub fn sim_matchup(args: FbsimLeagueSeasonWeekMatchupSimArgs) -> Result<(), String> {
-    // Load the league from its file as mutable
-    let file_res = &fs::read_to_string(&args.league);
-    let file = match file_res {
-        Ok(file) => file,
-        Err(error) => return Err(format!("Error loading league file: {}", error)),
-    };
-    let league_res = serde_json::from_str(file);
-    let mut league: League = match league_res {
-        Ok(league) => league,
-        Err(error) => return Err(format!("Error loading league from file: {}", error)),
-    };
+pub fn sim_matchup(args: FbsimLeagueSeasonWeekMatchupSimArgs, seed: Option<u64>, wait: bool) -> Result<(), String> {
+    // Lock the league and load its current season as mutable, with only the
+    // game of the matchup
+    let scope = LeagueScope::WeekMatchup(None, args.week, args.matchup);
+    let (_lock, mut league) = open_league(&args.league, &scope, LockMode::Exclusive, wait)?;
 
     // Load the playback speed argument
     let playback_speed: f64 = args.playback_speed.unwrap_or(2.0);
 
     // Simulate the matchup
-    let mut rng = rand::thread_rng();
+    let mut rng = new_rng(seed)?;
     let mut stdout = stdout();
-    loop {
+    let mut scoreboard = if args.tui {
+        Some(Scoreboard::new(&mut stdout)?)
+    } else {
+        None
+    };
+    let final_game: Option<Game> = loop {
         // Simulate a play and then read the current drive for display
         let game_opt: Option<Game> = match league.sim_play(args.week, args.matchup, &mut rng) {
             Ok(game_opt) => game_opt,
@@ -59,51 +56,23 @@ pub fn sim_matchup(args: FbsimLeagueSeasonWeekMatchupSimArgs) -> Result<(), Stri
             Some(d) => d,
             None => return Err(String::from("No drive found in current game"))
         };
-        let drive_str = format!("{}", drive);
-        let drive_str_len = drive_str.matches("\n").count() as u16;
-        if stdout.write_all(drive_str.as_bytes()).is_err() {
-            return Err(String::from("Failed to write drive to stdout"));
-        }
-        if stdout.flush().is_err() {
-            return Err(String::from("Failed to flush stdout"));
+        match scoreboard.as_mut() {
+            Some(sb) => {
+                let play = match drive.plays().last() {
+                    Some(p) => p,
+                    None => return Err(String::from("No plays found in current drive"))
+                };
+                sb.playback_play(&mut stdout, play, matchup.context(), playback_speed)?;
+            },
+            None => playback_drive(&mut stdout, drive, playback_speed)?
         }
 
-        // Wait based on the duration of the play
-        let play = match drive.plays().last() {
-            Some(p) => p,
-            None => return Err(String::from("No plays found in current drive"))
-        };
-        let play_duration = play.result().play_duration();
-        let post_play_duration = match play.post_play() {
-            PlayTypeResult::BetweenPlay(res) => 20.max(res.duration()),
-            _ => 30
-        };
-        let duration = play_duration + post_play_duration;
-        let wait_time = (duration * 250) as f64 / playback_speed;
-        let one_sec = time::Duration::from_millis(wait_time.round().abs() as u64);
-        thread::sleep(one_sec);
-
-        // Reset the cursor if drive is not complete
-        if !drive.complete() {
-            let errmsg = String::from("Failed to reset cursor");
-            if stdout.queue(cursor::MoveUp(drive_str_len)).is_err() {
-                return Err(errmsg);
-            }
-            if stdout.queue(cursor::MoveToColumn(0)).is_err() {
-                return Err(errmsg);
-            }
-            if stdout.queue(terminal::Clear(terminal::ClearType::FromCursorDown)).is_err() {
-                return Err(errmsg);
-            }
-        } else {
-            println!("\n");
-        }
-
-        // Break if the game is over
+        // Break with the final game if the game is over
         if matchup.context().game_over() {
-            break
+            break game_opt
         }
-    }
+    };
+    drop(scoreboard);
 
     // Print game-over message and final stats
     let season = match league.current_season() {
@@ -114,40 +83,19 @@ pub fn sim_matchup(args: FbsimLeagueSeasonWeekMatchupSimArgs) -> Result<(), Stri
         Some(m) => m,
         None => return Err(String::from("Failed to get matchup after simulating game"))
     };
-    let home_stats = match matchup.home_stats() {
-        Some(s) => s,
-        None => return Err(String::from("Failed to get home stats after simulating game"))
-    };
-    let away_stats = match matchup.away_stats() {
-        Some(s) => s,
-        None => return Err(String::from("Failed to get away stats after simulating game"))
-    };
-    let context = matchup.context();
-    println!("{} Game over", context);
-    println!();
-    println!(
-        "{} stats\n{}",
-        context.home_team_short(),
-        home_stats
-    );
-    println!();
-    println!(
-        "{} stats\n{}",
-        context.away_team_short(),
-        away_stats
-    );
-
-    // Serialize the league as JSON
-    let league_res = serde_json::to_string_pretty(&league);
-    let league_str: String = match league_res {
-        Ok(league_str) => league_str,
-        Err(error) => return Err(format!("Error serializing league: {}", error)),
+    let game = match final_game {
+        Some(g) => g,
+        None => return Err(String::from("Failed to get game after simulating game"))
     };
+    if args.drive_chart {
+        println!("{}", drive_chart(&game));
+    }
+    display_game_over(matchup.context(), &game);
 
     // Write the league back to its file
-    let write_res = fs::write(&args.league, league_str);
-    if let Err(e) = write_res {
-        return Err(format!("Error writing league file: {}", e));
-    }
+    save_league(&args.league, &league, &scope)?;
+
+    // If play-by-play export files were given, export the game to them
+    write_pbp(&game, &args.pbp_csv, &args.pbp_ndjson)?;
     Ok(())
 }
@@ -0,0 +1,971 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs;
+use std::io::{Read, Write};
+use std::path::Path;
+use std::str::FromStr;
+
+use fbsim_core::league::League;
+
+use crate::league::journal::record_snapshot;
+use crate::league::lock::{lock_league, LeagueLock, LockMode};
+
+use rusqlite::types::Value as SqlValue;
+use rusqlite::{params_from_iter, Connection, OpenFlags, OptionalExtension};
+use serde::{Deserialize, Serialize};
+use serde_json::{self, Map, Value};
+
+/// The header at the start of every SQLite database file
+const SQLITE_HEADER: &[u8] = b"SQLite format 3\0";
+
+/// The file extensions of SQLite leagues which do not exist yet
+const SQLITE_EXTENSIONS: [&str; 3] = ["db", "sqlite", "sqlite3"];
+
+/// A table of a SQLite league, keyed by integer columns
+///
+/// Each row of a table with data holds the JSON of its part of the league,
+/// while a row of a table without data only marks that its key exists.
+struct Table {
+    name: &'static str,
+    keys: &'static [&'static str],
+    data: bool
+}
+
+/// The tables of a SQLite league
+///
+/// Games are kept apart from their matchups, and the rounds of the playoff
+/// brackets apart from their season, so that simulating a play only
+/// rewrites its game. The winners bracket is stored as bracket -1.
+const TABLES: [Table; 9] = [
+    Table{ name: "teams", keys: &["id"], data: true },
+    Table{ name: "seasons", keys: &["year"], data: true },
+    Table{ name: "current_season", keys: &["year"], data: false },
+    Table{ name: "weeks", keys: &["year", "week"], data: false },
+    Table{ name: "matchups", keys: &["year", "week", "matchup"], data: true },
+    Table{ name: "games", keys: &["year", "week", "matchup"], data: true },
+    Table{ name: "playoff_rounds", keys: &["year", "bracket", "round"], data: false },
+    Table{ name: "playoff_matchups", keys: &["year", "bracket", "round", "matchup"], data: true },
+    Table{ name: "playoff_games", keys: &["year", "bracket", "round", "matchup"], data: true }
+];
+
+/// The bracket of the winners bracket in the playoff tables
+const WINNERS_BRACKET: i64 = -1;
+
+/// The tables holding a list of weeks, their matchups and their games
+type WeekTables = (&'static str, &'static str, &'static str);
+
+/// The tables holding the weeks of a regular season
+const SEASON_WEEKS: WeekTables = ("weeks", "matchups", "games");
+
+/// The tables holding the rounds of a playoff bracket
+const PLAYOFF_ROUNDS: WeekTables = ("playoff_rounds", "playoff_matchups", "playoff_games");
+
+impl Table {
+    /// Get a table of a SQLite league by its name
+    fn get(name: &str) -> Result<&'static Table, rusqlite::Error> {
+        match TABLES.iter().find(|t| t.name == name) {
+            Some(table) => Ok(table),
+            None => Err(rusqlite::Error::InvalidParameterName(format!("No league table named {}", name)))
+        }
+    }
+
+    /// The statement creating the table if it does not exist yet
+    fn create(&self) -> String {
+        let mut columns: Vec<String> = self.keys.iter().map(|k| format!("{} INTEGER NOT NULL", k)).collect();
+        if self.data {
+            columns.push(String::from("data TEXT NOT NULL"));
+        }
+        columns.push(format!("PRIMARY KEY ({})", self.keys.join(", ")));
+        format!("CREATE TABLE IF NOT EXISTS {} ({});", self.name, columns.join(", "))
+    }
+
+    /// The condition matching the rows whose key starts with a prefix of the
+    /// given length
+    fn filter(&self, len: usize) -> String {
+        let conditions: Vec<String> = self.keys[..len].iter().enumerate()
+            .map(|(i, k)| format!("{} = ?{}", k, i + 1))
+            .collect();
+        if conditions.is_empty() {
+            String::new()
+        } else {
+            format!(" WHERE {}", conditions.join(" AND "))
+        }
+    }
+
+    /// Read the rows of the table whose key starts with the given prefix
+    fn read(&self, conn: &Connection, prefix: &[i64], rows: &mut LeagueRows) -> Result<(), rusqlite::Error> {
+        let mut columns = self.keys.join(", ");
+        if self.data {
+            columns.push_str(", data");
+        }
+        let mut stmt = conn.prepare(&format!("SELECT {} FROM {}{}", columns, self.name, self.filter(prefix.len())))?;
+        let results = stmt.query_map(params_from_iter(prefix), |r| {
+            let key = (0..self.keys.len()).map(|i| r.get(i)).collect::<Result<Vec<i64>, _>>()?;
+            let data: String = if self.data { r.get(self.keys.len())? } else { String::new() };
+            Ok((key, data))
+        })?;
+        for result in results {
+            let (key, data) = result?;
+            rows.insert(self.name, key, data);
+        }
+        Ok(())
+    }
+
+    /// Write a row of the table, or delete it if it has no value
+    fn write(&self, conn: &Connection, key: &[i64], data: Option<&String>) -> Result<(), rusqlite::Error> {
+        let mut values: Vec<SqlValue> = key.iter().map(|k| SqlValue::Integer(*k)).collect();
+        match data {
+            Some(data) => {
+                let mut columns = self.keys.join(", ");
+                if self.data {
+                    columns.push_str(", data");
+                    values.push(SqlValue::Text(data.clone()));
+                }
+                let placeholders: Vec<String> = (1..=values.len()).map(|i| format!("?{}", i)).collect();
+                conn.execute(
+                    &format!("INSERT OR REPLACE INTO {} ({}) VALUES ({})", self.name, columns, placeholders.join(", ")),
+                    params_from_iter(values)
+                )?;
+            },
+            None => {
+                conn.execute(&format!("DELETE FROM {}{}", self.name, self.filter(key.len())), params_from_iter(values))?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Create the tables of a SQLite league which do not exist yet
+fn create_tables(conn: &Connection) -> Result<(), rusqlite::Error> {
+    for table in TABLES.iter() {
+        conn.execute_batch(&table.create())?;
+    }
+    Ok(())
+}
+
+/// The format a league is stored in
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum LeagueStore {
+    Json,
+    Sqlite
+}
+
+impl FromStr for LeagueStore {
+    type Err = String;
+    fn from_str(input: &str) -> Result<LeagueStore, Self::Err> {
+        match input {
+            "json"      => Ok(LeagueStore::Json),
+            "sqlite"    => Ok(LeagueStore::Sqlite),
+            _ => Err(format!("Invalid league store, expected json or sqlite: {}", input))
+        }
+    }
+}
+
+impl std::fmt::Display for LeagueStore {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let store_str = match self {
+            LeagueStore::Json => "json",
+            LeagueStore::Sqlite => "sqlite"
+        };
+        f.write_str(store_str)
+    }
+}
+
+impl LeagueStore {
+    /// Detect the format of a league file from its header, or from its
+    /// extension if it does not exist yet
+    pub fn detect(path: &str) -> LeagueStore {
+        if let Ok(mut file) = fs::File::open(path) {
+            let mut header = [0_u8; 16];
+            return match file.read_exact(&mut header) {
+                Ok(_) if header == SQLITE_HEADER => LeagueStore::Sqlite,
+                _ => LeagueStore::Json
+            };
+        }
+        let extension = Path::new(path).extension().and_then(|e| e.to_str()).unwrap_or("");
+        if SQLITE_EXTENSIONS.contains(&extension) {
+            LeagueStore::Sqlite
+        } else {
+            LeagueStore::Json
+        }
+    }
+}
+
+/// Parse an optional league store argument, defaulting to the given store
+pub fn parse_store(store: &Option<String>, default: LeagueStore) -> Result<LeagueStore, String> {
+    match store {
+        Some(s) => LeagueStore::from_str(s),
+        None => Ok(default)
+    }
+}
+
+/// The part of a league a command loads and saves
+///
+/// A JSON league is always loaded whole, while a SQLite league only reads
+/// the rows in scope, always including the league's teams. A season is read
+/// with all of its weeks and matchups, since fbsim-core validates a season
+/// as a whole, but only with the games the command needs.
+pub enum LeagueScope {
+    /// Every season of the league
+    All,
+    /// Only the teams of the league
+    Teams,
+    /// A season with all of its games, the current season if no year is given
+    Season(Option<usize>),
+    /// A season with only the game of the given week and matchup
+    WeekMatchup(Option<usize>, usize, usize),
+    /// A season with only the game of the given playoff round and matchup, of
+    /// a conference bracket or of the winners bracket if no conference is given
+    PlayoffMatchup(Option<usize>, Option<usize>, usize, usize)
+}
+
+impl LeagueScope {
+    /// The key prefix of the rows in scope of each table, leaving out the
+    /// tables with no rows in scope
+    fn prefixes(&self, conn: &Connection) -> Result<BTreeMap<&'static str, Vec<i64>>, rusqlite::Error> {
+        let year: Option<i64> = match self {
+            LeagueScope::All | LeagueScope::Teams => None,
+            LeagueScope::Season(year) |
+            LeagueScope::WeekMatchup(year, _, _) |
+            LeagueScope::PlayoffMatchup(year, _, _, _) => match year {
+                Some(year) => Some(*year as i64),
+                None => conn.query_row("SELECT year FROM current_season", [], |r| r.get(0)).optional()?
+            }
+        };
+        let mut prefixes = BTreeMap::new();
+        for table in TABLES.iter() {
+            let prefix = match (self, year, table.name) {
+                (LeagueScope::All, _, _) | (_, _, "teams") => Some(Vec::new()),
+                (LeagueScope::Teams, _, _) | (_, None, _) => None,
+                (LeagueScope::WeekMatchup(_, week, matchup), Some(year), "games") => {
+                    Some(vec![year, *week as i64, *matchup as i64])
+                },
+                (LeagueScope::PlayoffMatchup(_, conference, round, matchup), Some(year), "playoff_games") => {
+                    let bracket = conference.map(|c| c as i64).unwrap_or(WINNERS_BRACKET);
+                    Some(vec![year, bracket, *round as i64, *matchup as i64])
+                },
+                (LeagueScope::WeekMatchup(..), _, "playoff_games") |
+                (LeagueScope::PlayoffMatchup(..), _, "games") => None,
+                (_, Some(year), _) => Some(vec![year])
+            };
+            if let Some(prefix) = prefix {
+                prefixes.insert(table.name, prefix);
+            }
+        }
+        Ok(prefixes)
+    }
+}
+
+/// A row of a SQLite league, by its table and the values of its key columns
+type RowKey = (String, Vec<i64>);
+
+/// The rows of a league in its SQLite tables
+#[derive(Default, PartialEq, Clone)]
+struct LeagueRows(BTreeMap<RowKey, String>);
+
+/// The rows of a SQLite league changed by a command, each with its value
+/// before the change, or none if the command inserted it
+///
+/// Restoring these rows undoes the change without copying the whole league.
+#[derive(Default, Serialize, Deserialize)]
+pub struct LeagueRowsPatch {
+    rows: Vec<(String, Vec<i64>, Option<String>)>
+}
+
+impl LeagueRowsPatch {
+    /// The stored rows which differ from the given rows
+    fn between(stored: &LeagueRows, rows: &LeagueRows) -> LeagueRowsPatch {
+        let keys: BTreeSet<&RowKey> = stored.0.keys().chain(rows.0.keys()).collect();
+        LeagueRowsPatch{
+            rows: keys.into_iter()
+                .filter(|k| stored.0.get(k) != rows.0.get(k))
+                .map(|(table, key)| (table.clone(), key.clone(), stored.0.get(&(table.clone(), key.clone())).cloned()))
+                .collect()
+        }
+    }
+
+    /// Whether the patch changes no rows
+    fn is_empty(&self) -> bool {
+        self.rows.is_empty()
+    }
+
+    /// Merge the patch of a later change into this one, keeping the values
+    /// from before the earlier change
+    pub fn merge(&mut self, later: LeagueRowsPatch) {
+        for (table, key, value) in later.rows.into_iter() {
+            if !self.rows.iter().any(|(t, k, _)| *t == table && *k == key) {
+                self.rows.push((table, key, value));
+            }
+        }
+    }
+
+    /// Restore the values of the patched rows in the SQLite tables
+    fn apply(&self, conn: &Connection) -> Result<(), rusqlite::Error> {
+        for (table, key, value) in self.rows.iter() {
+            Table::get(table)?.write(conn, key, value.as_ref())?;
+        }
+        Ok(())
+    }
+}
+
+/// Serialize a JSON value as a row's data
+fn row_data(value: &Value) -> Result<String, String> {
+    match serde_json::to_string(value) {
+        Ok(s) => Ok(s),
+        Err(e) => Err(format!("Error serializing league: {}", e))
+    }
+}
+
+/// Parse a row's data as a JSON value
+fn parse_row(data: &str) -> Result<Value, String> {
+    match serde_json::from_str(data) {
+        Ok(v) => Ok(v),
+        Err(e) => Err(format!("Error loading league from file: {}", e))
+    }
+}
+
+/// Take a key out of a JSON object of a league, failing if it is missing
+///
+/// The rows of a league are split out by the keys fbsim-core serializes it
+/// with, so a missing key means its layout changed and must not be guessed.
+fn take_key(value: &mut Value, key: &str, parent: &str) -> Result<Value, String> {
+    match value.as_object_mut().and_then(|o| o.remove(key)) {
+        Some(v) => Ok(v),
+        None => Err(format!("Error serializing league: Missing {} in {}", key, parent))
+    }
+}
+
+/// Take an array out of a JSON object of a league, failing if it is missing
+fn take_array(value: &mut Value, key: &str, parent: &str) -> Result<Vec<Value>, String> {
+    match take_key(value, key, parent)? {
+        Value::Array(array) => Ok(array),
+        v => Err(format!("Error serializing league: Expected {} in {} to be an array: {}", key, parent, v))
+    }
+}
+
+/// Parse the key of a conference bracket as its bracket in the playoff tables
+fn parse_bracket(conference: &str) -> Result<i64, String> {
+    match conference.parse::<usize>() {
+        Ok(bracket) => Ok(bracket as i64),
+        Err(e) => Err(format!("Error serializing league: Invalid conference bracket {}: {}", conference, e))
+    }
+}
+
+impl LeagueRows {
+    /// Insert a row into a table
+    fn insert(&mut self, table: &str, key: Vec<i64>, data: String) {
+        self.0.insert((String::from(table), key), data);
+    }
+
+    /// Get a row of a table
+    fn get(&self, table: &str, key: &[i64]) -> Option<&String> {
+        self.0.get(&(String::from(table), key.to_vec()))
+    }
+
+    /// The rows of a table whose key extends the given prefix by one column,
+    /// in key order
+    fn children<'a>(&'a self, table: &'a str, prefix: &'a [i64]) -> impl Iterator<Item = (&'a Vec<i64>, &'a String)> + 'a {
+        self.0.range((String::from(table), prefix.to_vec())..)
+            .take_while(move |((t, k), _)| t == table && k.starts_with(prefix))
+            .filter(move |((_, k), _)| k.len() == prefix.len() + 1)
+            .map(|((_, k), data)| (k, data))
+    }
+
+    /// Split a league into the rows of its tables
+    fn from_league(league: &League) -> Result<LeagueRows, String> {
+        match serde_json::to_value(league) {
+            Ok(value) => LeagueRows::from_value(value),
+            Err(e) => Err(format!("Error serializing league: {}", e))
+        }
+    }
+
+    /// Split the JSON of a league into the rows of its tables
+    fn from_value(mut value: Value) -> Result<LeagueRows, String> {
+        let mut rows = LeagueRows::default();
+        let teams = match take_key(&mut value, "teams", "league")? {
+            Value::Object(teams) => teams,
+            v => return Err(format!("Error serializing league: Expected teams in league to be an object: {}", v))
+        };
+        for (id, team) in teams.iter() {
+            let id: i64 = match id.parse() {
+                Ok(id) => id,
+                Err(e) => return Err(format!("Error serializing league: Invalid team ID {}: {}", id, e))
+            };
+            rows.insert("teams", vec![id], row_data(team)?);
+        }
+        let mut seasons: Vec<(bool, Value)> = Vec::new();
+        match take_key(&mut value, "current_season", "league")? {
+            Value::Null => (),
+            season => seasons.push((true, season))
+        }
+        seasons.extend(take_array(&mut value, "seasons", "league")?.into_iter().map(|s| (false, s)));
+        for (current, mut season) in seasons.into_iter() {
+            let year = match season.get("year").and_then(Value::as_i64) {
+                Some(year) => year,
+                None => return Err(String::from("Error serializing league: Missing or invalid year in season"))
+            };
+            if rows.get("seasons", &[year]).is_some() {
+                return Err(format!("Error serializing league: Duplicate season year: {}", year));
+            }
+
+            // Split the weeks and the rounds of the playoff brackets out of
+            // the season, keeping the keys of its conference brackets
+            let weeks = take_array(&mut season, "weeks", "season")?;
+            rows.insert_weeks(SEASON_WEEKS, &[year], weeks)?;
+            let playoffs = match season.get_mut("playoffs") {
+                Some(playoffs) => playoffs,
+                None => return Err(String::from("Error serializing league: Missing playoffs in season"))
+            };
+            let brackets = match take_key(playoffs, "conference_brackets", "playoffs")? {
+                Value::Object(brackets) => brackets,
+                v => return Err(format!("Error serializing league: Expected conference_brackets in playoffs to be an object: {}", v))
+            };
+            let mut conferences = Map::new();
+            for (conference, rounds) in brackets.into_iter() {
+                let rounds = match rounds {
+                    Value::Array(rounds) => rounds,
+                    v => return Err(format!("Error serializing league: Expected conference bracket {} to be an array: {}", conference, v))
+                };
+                rows.insert_weeks(PLAYOFF_ROUNDS, &[year, parse_bracket(&conference)?], rounds)?;
+                conferences.insert(conference, Value::Array(Vec::new()));
+            }
+            let winners_bracket = take_array(playoffs, "winners_bracket", "playoffs")?;
+            rows.insert_weeks(PLAYOFF_ROUNDS, &[year, WINNERS_BRACKET], winners_bracket)?;
+            if let Some(p) = playoffs.as_object_mut() {
+                p.insert(String::from("conference_brackets"), Value::Object(conferences));
+            }
+            if current {
+                rows.insert("current_season", vec![year], String::new());
+            }
+            rows.insert("seasons", vec![year], row_data(&season)?);
+        }
+        Ok(rows)
+    }
+
+    /// Split weeks and their matchups and games into rows under a key prefix
+    fn insert_weeks(&mut self, tables: WeekTables, prefix: &[i64], weeks: Vec<Value>) -> Result<(), String> {
+        let (week_table, matchup_table, game_table) = tables;
+        for (i, mut week) in weeks.into_iter().enumerate() {
+            let week_key = [prefix, &[i as i64]].concat();
+            let matchups = take_array(&mut week, "matchups", "week")?;
+            if let Some(key) = week.as_object().and_then(|w| w.keys().next()) {
+                return Err(format!("Error serializing league: Unknown key {} in week", key));
+            }
+            for (j, mut matchup) in matchups.into_iter().enumerate() {
+                let key = [&week_key[..], &[j as i64]].concat();
+                let game = take_key(&mut matchup, "game", "matchup")?;
+                if !game.is_null() {
+                    self.insert(game_table, key.clone(), row_data(&game)?);
+                }
+                self.insert(matchup_table, key, row_data(&matchup)?);
+            }
+            self.insert(week_table, week_key, String::new());
+        }
+        Ok(())
+    }
+
+    /// Reassemble the weeks under a key prefix from their rows
+    fn collect_weeks(&self, tables: WeekTables, prefix: &[i64]) -> Result<Vec<Value>, String> {
+        let (week_table, matchup_table, game_table) = tables;
+        let mut weeks: Vec<Value> = Vec::new();
+        for (week_key, _) in self.children(week_table, prefix) {
+            let mut matchups: Vec<Value> = Vec::new();
+            for (key, data) in self.children(matchup_table, week_key) {
+                let mut matchup = parse_row(data)?;
+                let game = match self.get(game_table, key) {
+                    Some(data) => parse_row(data)?,
+                    None => Value::Null
+                };
+                match matchup.as_object_mut() {
+                    Some(m) => m.insert(String::from("game"), game),
+                    None => return Err(format!("Error loading league from file: Invalid matchup: {}", data))
+                };
+                matchups.push(matchup);
+            }
+            let mut week = Map::new();
+            week.insert(String::from("matchups"), Value::Array(matchups));
+            weeks.push(Value::Object(week));
+        }
+        Ok(weeks)
+    }
+
+    /// Reassemble a league from the rows of its tables
+    fn to_league(&self) -> Result<League, String> {
+        let mut teams = Map::new();
+        for (key, data) in self.children("teams", &[]) {
+            teams.insert(key[0].to_string(), parse_row(data)?);
+        }
+        let mut current_season = Value::Null;
+        let mut seasons: Vec<Value> = Vec::new();
+        for (key, data) in self.children("seasons", &[]) {
+            let year = key[0];
+            let mut season = parse_row(data)?;
+            let weeks = self.collect_weeks(SEASON_WEEKS, &[year])?;
+            let playoffs = match season.get_mut("playoffs").and_then(Value::as_object_mut) {
+                Some(playoffs) => playoffs,
+                None => return Err(format!("Error loading league from file: Invalid season: {}", data))
+            };
+            let conferences: Vec<String> = match playoffs.get("conference_brackets").and_then(Value::as_object) {
+                Some(brackets) => brackets.keys().cloned().collect(),
+                None => return Err(format!("Error loading league from file: Invalid season: {}", data))
+            };
+            let mut brackets = Map::new();
+            for conference in conferences.into_iter() {
+                let rounds = self.collect_weeks(PLAYOFF_ROUNDS, &[year, parse_bracket(&conference)?])?;
+                brackets.insert(conference, Value::Array(rounds));
+            }
+            playoffs.insert(String::from("conference_brackets"), Value::Object(brackets));
+            playoffs.insert(
+                String::from("winners_bracket"),
+                Value::Array(self.collect_weeks(PLAYOFF_ROUNDS, &[year, WINNERS_BRACKET])?)
+            );
+            if let Some(s) = season.as_object_mut() {
+                s.insert(String::from("weeks"), Value::Array(weeks));
+            }
+            if self.get("current_season", &[year]).is_some() {
+                current_season = season;
+            } else {
+                seasons.push(season);
+            }
+        }
+        let mut league = Map::new();
+        league.insert(String::from("teams"), Value::Object(teams));
+        league.insert(String::from("current_season"), current_season);
+        league.insert(String::from("seasons"), Value::Array(seasons));
+        match serde_json::from_value(Value::Object(league)) {
+            Ok(league) => Ok(league),
+            Err(e) => Err(format!("Error loading league from file: {}", e))
+        }
+    }
+
+    /// Read the rows of a league in the given scope from its SQLite tables
+    fn read(conn: &Connection, scope: &LeagueScope) -> Result<LeagueRows, rusqlite::Error> {
+        let mut rows = LeagueRows::default();
+        for (table, prefix) in scope.prefixes(conn)?.iter() {
+            Table::get(table)?.read(conn, prefix, &mut rows)?;
+        }
+        Ok(rows)
+    }
+
+    /// Read the stored rows these rows would replace: the rows in the scope
+    /// they were loaded from, and the rows under the keys of any rows the
+    /// command added outside of it, such as a new season
+    fn stored(&self, conn: &Connection, scope: &LeagueScope) -> Result<LeagueRows, rusqlite::Error> {
+        let prefixes = scope.prefixes(conn)?;
+        let mut stored = LeagueRows::read(conn, scope)?;
+        for (table, key) in self.0.keys() {
+            if !prefixes.get(table.as_str()).is_some_and(|prefix| key.starts_with(prefix)) {
+                Table::get(table)?.read(conn, key, &mut stored)?;
+            }
+        }
+        Ok(stored)
+    }
+
+    /// Write the rows changed by a patch to the SQLite tables, deleting the
+    /// rows which no longer exist
+    fn write(&self, conn: &Connection, patch: &LeagueRowsPatch) -> Result<(), rusqlite::Error> {
+        for (table, key, _) in patch.rows.iter() {
+            Table::get(table)?.write(conn, key, self.get(table, key))?;
+        }
+        Ok(())
+    }
+}
+
+/// Load the part of a league in the given scope from its file in either
+/// format
+fn load_league(path: &str, scope: &LeagueScope) -> Result<League, String> {
+    match LeagueStore::detect(path) {
+        LeagueStore::Json => {
+            let file = match fs::read_to_string(path) {
+                Ok(file) => file,
+                Err(error) => return Err(format!("Error loading league file: {}", error)),
+            };
+            match serde_json::from_str(&file) {
+                Ok(league) => Ok(league),
+                Err(error) => Err(format!("Error loading league from file: {}", error)),
+            }
+        },
+        LeagueStore::Sqlite => {
+            let rows_res = Connection::open_with_flags(path, OpenFlags::SQLITE_OPEN_READ_ONLY)
+                .and_then(|conn| LeagueRows::read(&conn, scope));
+            match rows_res {
+                Ok(rows) => rows.to_league(),
+                Err(error) => Err(format!("Error loading league file: {}", error)),
+            }
+        }
+    }
+}
+
+/// Lock a league in the given mode and load the part of it in the given
+/// scope, the lock is held until the returned guard is dropped
+pub fn open_league(path: &str, scope: &LeagueScope, mode: LockMode, wait: bool) -> Result<(LeagueLock, League), String> {
+    let lock = lock_league(path, mode, wait)?;
+    let league = load_league(path, scope)?;
+    Ok((lock, league))
+}
+
+/// Write a file by writing a temporary file next to it and renaming it into
+/// place, so an interrupted write never leaves a partial file behind
+pub fn write_atomic(path: &str, contents: &[u8]) -> Result<(), std::io::Error> {
+    let tmp_path = format!("{}.tmp", path);
+    let mut file = fs::File::create(&tmp_path)?;
+    file.write_all(contents)?;
+    file.sync_all()?;
+    fs::rename(&tmp_path, path)
+}
+
+/// Save a league loaded in the given scope back to its file in the format it
+/// is already stored in, snapshotting the previous league into its journal
+/// first
+pub fn save_league(path: &str, league: &League, scope: &LeagueScope) -> Result<(), String> {
+    save_league_in_group(path, league, scope, None)
+}
+
+/// Save a league back to its file, sharing one snapshot with the previous
+/// save if it was in the same group
+///
+/// This keeps a run of small changes, such as the plays of a matchup, from
+/// pushing older snapshots out of the journal.
+pub fn save_league_in_group(path: &str, league: &League, scope: &LeagueScope, group: Option<&str>) -> Result<(), String> {
+    if LeagueStore::detect(path) == LeagueStore::Json || !Path::new(path).exists() {
+        record_snapshot(path, group, None)?;
+        return write_league(path, league, LeagueStore::detect(path));
+    }
+
+    // Write only the rows of a SQLite league in scope which changed, and
+    // snapshot them first
+    let rows = LeagueRows::from_league(league)?;
+    let mut conn = match Connection::open(path).and_then(|conn| create_tables(&conn).map(|_| conn)) {
+        Ok(conn) => conn,
+        Err(e) => return Err(format!("Error writing league file: {}", e)),
+    };
+    let tx = match conn.transaction() {
+        Ok(tx) => tx,
+        Err(e) => return Err(format!("Error writing league file: {}", e)),
+    };
+    let patch = match rows.stored(&tx, scope) {
+        Ok(stored) => LeagueRowsPatch::between(&stored, &rows),
+        Err(e) => return Err(format!("Error loading league file: {}", e)),
+    };
+    if patch.is_empty() {
+        return Ok(());
+    }
+    if let Err(e) = rows.write(&tx, &patch) {
+        return Err(format!("Error writing league file: {}", e));
+    }
+    record_snapshot(path, group, Some(patch))?;
+    if let Err(e) = tx.commit() {
+        return Err(format!("Error writing league file: {}", e));
+    }
+    Ok(())
+}
+
+/// Restore the rows of a SQLite league from a snapshot of its changed rows
+pub fn restore_league_rows(path: &str, patch: &LeagueRowsPatch) -> Result<(), String> {
+    let restore_res = Connection::open(path).and_then(|mut conn| {
+        let tx = conn.transaction()?;
+        patch.apply(&tx)?;
+        tx.commit()
+    });
+    match restore_res {
+        Ok(_) => Ok(()),
+        Err(e) => Err(format!("Error writing league file: {}", e))
+    }
+}
+
+/// Write a league to a file in the given format, replacing the file if it is
+/// in the other format
+pub fn write_league(path: &str, league: &League, store: LeagueStore) -> Result<(), String> {
+    match store {
+        LeagueStore::Json => {
+            let league_str = match serde_json::to_string_pretty(league) {
+                Ok(league_str) => league_str,
+                Err(error) => return Err(format!("Error serializing league: {}", error)),
+            };
+            if let Err(e) = write_atomic(path, league_str.as_bytes()) {
+                return Err(format!("Error writing league file: {}", e));
+            }
+        },
+        LeagueStore::Sqlite => {
+            let rows = LeagueRows::from_league(league)?;
+            if Path::new(path).exists() && LeagueStore::detect(path) != LeagueStore::Sqlite {
+                if let Err(e) = fs::remove_file(path) {
+                    return Err(format!("Error writing league file: {}", e));
+                }
+            }
+            let write_res = Connection::open(path).and_then(|mut conn| {
+                create_tables(&conn)?;
+                let tx = conn.transaction()?;
+                let stored = rows.stored(&tx, &LeagueScope::All)?;
+                rows.write(&tx, &LeagueRowsPatch::between(&stored, &rows))?;
+                tx.commit()
+            });
+            if let Err(e) = write_res {
+                return Err(format!("Error writing league file: {}", e));
+            }
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use fbsim_core::league::season::{LeagueSeasonPlayoffOptions, LeagueSeasonScheduleOptions};
+    use fbsim_core::team::FootballTeam;
+
+    use crate::rng::new_rng;
+
+    /// Add teams and a simulated regular season to the current season
+    fn sim_season(league: &mut League, rng: &mut rand::rngs::StdRng) {
+        for id in 0..4 {
+            league.add_season_team(id, FootballTeam::new()).unwrap();
+        }
+        league.generate_schedule(LeagueSeasonScheduleOptions::new(), rng).unwrap();
+        league.sim(rng).unwrap();
+    }
+
+    /// A league with a past season, its playoffs and a game in progress
+    fn test_league() -> League {
+        let mut rng = new_rng(Some(7)).unwrap();
+        let mut league = League::new();
+        for _ in 0..4 {
+            league.add_team();
+        }
+
+        // Simulate a past season and its playoffs
+        league.add_season().unwrap();
+        sim_season(&mut league, &mut rng);
+        let season = league.current_season_mut().as_mut().unwrap();
+        let mut options = LeagueSeasonPlayoffOptions::new();
+        options.num_playoff_teams = 4;
+        season.generate_playoffs(options, &mut rng).unwrap();
+        season.sim_playoffs(&mut rng).unwrap();
+
+        // Start the next season and simulate a few plays of its first game
+        league.add_season().unwrap();
+        for id in 0..4 {
+            league.add_season_team(id, FootballTeam::new()).unwrap();
+        }
+        league.generate_schedule(LeagueSeasonScheduleOptions::new(), &mut rng).unwrap();
+        for _ in 0..10 {
+            league.sim_play(0, 0, &mut rng).unwrap();
+        }
+        league
+    }
+
+    /// The keys of a JSON object
+    fn keys(value: &Value) -> Vec<&str> {
+        value.as_object().unwrap().keys().map(String::as_str).collect()
+    }
+
+    /// A path for a SQLite league in the temporary directory, with no league
+    /// or journal left there by an earlier run
+    fn temp_league(name: &str) -> String {
+        let path = std::env::temp_dir().join(format!("fbsim-store-{}-{}.db", name, std::process::id()));
+        let path = path.to_str().unwrap().to_string();
+        remove_league(&path);
+        path
+    }
+
+    /// Remove a league from the temporary directory with its lock and journal
+    fn remove_league(path: &str) {
+        let _ = fs::remove_file(path);
+        let _ = fs::remove_file(format!("{}.lock", path));
+        let _ = fs::remove_dir_all(format!("{}.history", path));
+    }
+
+    /// The keys of the rows of a table
+    fn table_keys(rows: &LeagueRows, table: &str) -> Vec<Vec<i64>> {
+        rows.0.keys().filter(|(t, _)| t == table).map(|(_, k)| k.clone()).collect()
+    }
+
+    #[test]
+    fn test_league_layout() {
+        // The rows are split out by these keys of the league's JSON, so a
+        // change to its layout in fbsim-core must fail here first
+        let value = serde_json::to_value(test_league()).unwrap();
+        assert_eq!(keys(&value), ["current_season", "seasons", "teams"]);
+        let season = &value["current_season"];
+        assert!(season["year"].is_u64());
+        assert!(season["weeks"].is_array());
+        assert_eq!(keys(&season["weeks"][0]), ["matchups"]);
+        assert!(keys(&season["weeks"][0]["matchups"][0]).contains(&"game"));
+        assert!(season["weeks"][0]["matchups"][0]["game"].is_object());
+        let playoffs = &value["seasons"][0]["playoffs"];
+        assert!(value["seasons"][0]["year"].is_u64());
+        assert!(playoffs["conference_brackets"].is_object());
+        assert!(playoffs["winners_bracket"].is_array());
+        assert_eq!(keys(&playoffs["conference_brackets"]["0"][0]), ["matchups"]);
+    }
+
+    #[test]
+    fn test_rows_missing_keys() {
+        let value = serde_json::to_value(test_league()).unwrap();
+        for (parent, key) in [("", "seasons"), ("", "current_season"), ("/current_season", "year"),
+            ("/current_season", "weeks"), ("/current_season/weeks/0", "matchups"),
+            ("/current_season/weeks/0/matchups/0", "game"), ("/seasons/0", "playoffs"),
+            ("/seasons/0/playoffs", "conference_brackets"), ("/seasons/0/playoffs", "winners_bracket")] {
+            let mut changed = value.clone();
+            changed.pointer_mut(parent).unwrap().as_object_mut().unwrap().remove(key);
+            assert!(LeagueRows::from_value(changed).is_err(), "{}/{}", parent, key);
+        }
+    }
+
+    #[test]
+    fn test_rows_duplicate_year() {
+        let mut value = serde_json::to_value(test_league()).unwrap();
+        let year = value["current_season"]["year"].clone();
+        value["seasons"][0]["year"] = year;
+        assert!(LeagueRows::from_value(value).is_err());
+    }
+
+    #[test]
+    fn test_rows_round_trip_empty_league() {
+        let league = League::new();
+        let rows = LeagueRows::from_league(&league).unwrap();
+        assert_eq!(rows.to_league().unwrap(), league);
+    }
+
+    #[test]
+    fn test_rows_round_trip() {
+        let league = test_league();
+        assert_eq!(league.seasons().len(), 1);
+        assert!(league.seasons()[0].playoffs().complete());
+        let current = league.current_season().as_ref().unwrap();
+        let matchup = league.matchup(*current.year(), 0, 0).unwrap();
+        assert!(matchup.context().started() && !matchup.context().game_over());
+
+        let rows = LeagueRows::from_league(&league).unwrap();
+        let year = *current.year() as i64;
+        assert_eq!(table_keys(&rows, "games"), [vec![year, 0, 0]]);
+        assert_eq!(table_keys(&rows, "current_season"), [vec![year]]);
+        assert_eq!(rows.to_league().unwrap(), league);
+    }
+
+    #[test]
+    fn test_rows_playoff_tables() {
+        // The playoff rounds are split out of their season, which only keeps
+        // the keys of its conference brackets
+        let league = test_league();
+        let year = *league.seasons()[0].year() as i64;
+        let rows = LeagueRows::from_league(&league).unwrap();
+        let rounds = table_keys(&rows, "playoff_rounds");
+        assert!(!rounds.is_empty());
+        assert!(rounds.iter().all(|k| k[..2] == [year, 0]));
+        assert_eq!(table_keys(&rows, "playoff_matchups").len(), 3);
+        let season = parse_row(rows.get("seasons", &[year]).unwrap()).unwrap();
+        assert!(season.get("weeks").is_none());
+        assert!(season["playoffs"].get("winners_bracket").is_none());
+        assert_eq!(season["playoffs"]["conference_brackets"]["0"], Value::Array(Vec::new()));
+    }
+
+    #[test]
+    fn test_patch_merge() {
+        // A row changed by both patches keeps its value from before the
+        // earlier patch
+        let row = |id: i64, data: Option<&str>| (String::from("teams"), vec![id], data.map(String::from));
+        let mut patch = LeagueRowsPatch{ rows: vec![row(0, Some("first"))] };
+        patch.merge(LeagueRowsPatch{ rows: vec![row(0, Some("second")), row(1, None)] });
+        assert_eq!(patch.rows, [row(0, Some("first")), row(1, None)]);
+
+        // Restoring a merged patch rewinds both changes
+        let mut league = League::new();
+        for _ in 0..4 {
+            league.add_team();
+        }
+        league.add_season().unwrap();
+        let first = LeagueRows::from_league(&league).unwrap();
+        league.add_season_team(0, FootballTeam::new()).unwrap();
+        let second = LeagueRows::from_league(&league).unwrap();
+        league.add_team();
+        league.add_season_team(1, FootballTeam::new()).unwrap();
+        let third = LeagueRows::from_league(&league).unwrap();
+        let mut patch = LeagueRowsPatch::between(&first, &second);
+        patch.merge(LeagueRowsPatch::between(&second, &third));
+
+        let conn = Connection::open_in_memory().unwrap();
+        create_tables(&conn).unwrap();
+        third.write(&conn, &LeagueRowsPatch::between(&LeagueRows::default(), &third)).unwrap();
+        patch.apply(&conn).unwrap();
+        assert!(LeagueRows::read(&conn, &LeagueScope::All).unwrap() == first);
+    }
+
+    #[test]
+    fn test_rows_round_trip_sqlite() {
+        let league = test_league();
+        let rows = LeagueRows::from_league(&league).unwrap();
+        let mut conn = Connection::open_in_memory().unwrap();
+        create_tables(&conn).unwrap();
+        let tx = conn.transaction().unwrap();
+        rows.write(&tx, &LeagueRowsPatch::between(&LeagueRows::default(), &rows)).unwrap();
+        tx.commit().unwrap();
+
+        let stored = LeagueRows::read(&conn, &LeagueScope::All).unwrap();
+        assert!(stored == rows);
+        assert_eq!(stored.to_league().unwrap(), league);
+    }
+
+    #[test]
+    fn test_load_scopes() {
+        let league = test_league();
+        let path = temp_league("load-scopes");
+        write_league(&path, &league, LeagueStore::Sqlite).unwrap();
+        let current = league.current_season().clone().unwrap();
+        let year = *current.year();
+
+        // Teams are loaded in every scope, and seasons only in their own
+        assert_eq!(load_league(&path, &LeagueScope::All).unwrap(), league);
+        let teams = load_league(&path, &LeagueScope::Teams).unwrap();
+        assert_eq!(teams.teams(), league.teams());
+        assert!(teams.current_season().is_none() && teams.seasons().is_empty());
+        let season = load_league(&path, &LeagueScope::Season(None)).unwrap();
+        assert_eq!(season.current_season().as_ref(), Some(&current));
+        assert!(season.seasons().is_empty());
+        let past = load_league(&path, &LeagueScope::Season(Some(year - 1))).unwrap();
+        assert!(past.current_season().is_none());
+        assert_eq!(past.seasons(), league.seasons());
+
+        // Games are only loaded for the matchup in scope
+        let matchup = load_league(&path, &LeagueScope::WeekMatchup(None, 0, 0)).unwrap();
+        assert!(matchup.matchup(year, 0, 0).unwrap().game().is_some());
+        let other = load_league(&path, &LeagueScope::WeekMatchup(Some(year), 0, 1)).unwrap();
+        assert!(other.matchup(year, 0, 0).unwrap().game().is_none());
+        let playoff = load_league(&path, &LeagueScope::PlayoffMatchup(None, Some(0), 0, 0)).unwrap();
+        assert!(playoff.matchup(year, 0, 0).unwrap().game().is_none());
+        remove_league(&path);
+    }
+
+    #[test]
+    fn test_save_scopes() {
+        let mut league = test_league();
+        let path = temp_league("save-scopes");
+        write_league(&path, &league, LeagueStore::Sqlite).unwrap();
+
+        // Simulate a play on a league loaded with only its matchup's game,
+        // and on the whole league
+        let scope = LeagueScope::WeekMatchup(None, 0, 0);
+        let mut scoped = load_league(&path, &scope).unwrap();
+        scoped.sim_play(0, 0, &mut new_rng(Some(3)).unwrap()).unwrap();
+        league.sim_play(0, 0, &mut new_rng(Some(3)).unwrap()).unwrap();
+
+        // Saving the scoped league only changes its rows in the whole league
+        save_league(&path, &scoped, &scope).unwrap();
+        assert_eq!(load_league(&path, &LeagueScope::All).unwrap(), league);
+
+        // Seasons left out of the scope are not deleted
+        let mut teams = load_league(&path, &LeagueScope::Teams).unwrap();
+        teams.add_team();
+        league.add_team();
+        save_league(&path, &teams, &LeagueScope::Teams).unwrap();
+        assert_eq!(load_league(&path, &LeagueScope::All).unwrap(), league);
+
+        // A game started outside the scope is saved too
+        let mut scoped = load_league(&path, &scope).unwrap();
+        scoped.sim_play(0, 1, &mut new_rng(Some(5)).unwrap()).unwrap();
+        league.sim_play(0, 1, &mut new_rng(Some(5)).unwrap()).unwrap();
+        save_league(&path, &scoped, &scope).unwrap();
+        assert_eq!(load_league(&path, &LeagueScope::All).unwrap(), league);
+        remove_league(&path);
+    }
+}
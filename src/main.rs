@@ -1,6 +1,10 @@
+mod benchmark;
 mod cli;
+mod config;
 mod game;
 mod league;
+mod rng;
+mod team;
 
 use std::process;
 
@@ -8,7 +12,8 @@ use crate::cli::fbsim::{
     FbsimCli,
     FbsimSubcommand
 };
-use crate::cli::game::FbsimGameSubcommand;
+use crate::cli::game::{FbsimGameBenchmarkSubcommand, FbsimGameSubcommand};
+use crate::cli::game::context::FbsimGameContextSubcommand;
 use crate::cli::game::drive::FbsimGameDriveSubcommand;
 use crate::cli::game::play::FbsimGamePlaySubcommand;
 use crate::cli::game::score::FbsimGameScoreSubcommand;
@@ -27,15 +32,27 @@ use crate::cli::league::season::playoffs::round::matchup::FbsimLeagueSeasonPlayo
 use crate::cli::league::season::week::FbsimLeagueSeasonWeekSubcommand;
 use crate::cli::league::season::week::matchup::FbsimLeagueSeasonWeekMatchupSubcommand;
 use crate::cli::league::season::week::matchup::play::FbsimLeagueSeasonWeekMatchupPlaySubcommand;
+use crate::cli::team::FbsimTeamSubcommand;
+use crate::config::FbsimConfig;
 
 use crate::game::game_sim;
-use crate::game::game_benchmark;
+use crate::game::benchmark::game_benchmark;
+use crate::game::benchmark::compare::game_benchmark_compare;
+use crate::game::coach::game_coach;
+use crate::game::decide::game_decide;
+use crate::game::context::{context_new, context_set, context_show};
+use crate::game::replay::game_replay;
+use crate::game::series::game_series;
+use crate::game::winprob::game_winprob;
+use crate::game::drive::benchmark::drive_benchmark;
 use crate::game::drive::sim::drive_sim;
 use crate::game::play::benchmark::play_benchmark;
 use crate::game::play::sim::play_sim;
 use crate::game::score::benchmark::final_score_sim_benchmark;
 use crate::game::score::sim::final_score_sim;
+use crate::league::convert::convert_league;
 use crate::league::create::create_league;
+use crate::league::journal::{league_history, undo_league};
 use crate::league::team::add::add_team;
 use crate::league::team::get::get_team;
 use crate::league::team::list::list_teams;
@@ -75,100 +92,160 @@ use crate::league::season::week::sim::sim_season_week;
 use crate::league::season::week::matchup::get::get_matchup;
 use crate::league::season::week::matchup::sim::sim_matchup;
 use crate::league::season::week::matchup::play::sim::sim_play;
+use crate::team::compare::compare_teams;
+use crate::team::gen::gen_teams;
+use crate::team::validate::validate_teams;
 
-use clap::Parser;
+use clap::{CommandFactory, FromArgMatches};
+use clap::error::ErrorKind;
 
 fn main() {
-    // Parse the command-line args
-    let fbdb_cli = FbsimCli::parse();
+    // Load the project config file, whose league is the default league path
+    // unless one is set in the environment
+    let config_res = FbsimConfig::load();
+    let mut cli_command = <FbsimCli as CommandFactory>::command();
+    if let Ok(config) = &config_res {
+        cli_command = config.default_league(cli_command);
+    }
+
+    // Parse the command-line args, showing a broken config file alongside a
+    // missing arg since it may be why the league was not found
+    let matches_res = cli_command.try_get_matches()
+        .and_then(|matches| FbsimCli::from_arg_matches(&matches));
+    let fbdb_cli = match matches_res {
+        Ok(fbdb_cli) => fbdb_cli,
+        Err(error) => {
+            if let (Err(config_error), ErrorKind::MissingRequiredArgument) = (&config_res, error.kind()) {
+                eprintln!("{}", config_error);
+            }
+            error.exit()
+        }
+    };
+
+    // Fill the args not given from the config, a broken config file is only
+    // an error for commands which use it
+    let mut command = fbdb_cli.command();
+    match &config_res {
+        Ok(config) => {
+            config.apply(&mut command);
+        },
+        Err(error) if FbsimConfig::default().apply(&mut command) => {
+            println!("{}", error);
+            process::exit(1);
+        },
+        Err(_) => ()
+    }
 
     // Perform the subcommand
-    let command = fbdb_cli.command();
+    let seed = fbdb_cli.seed();
+    let wait = fbdb_cli.wait();
     let command_res = match &command {
         FbsimSubcommand::Game { command } => match command {
-            FbsimGameSubcommand::Benchmark(args) => game_benchmark(args.clone()),
-            FbsimGameSubcommand::Sim(args) => game_sim(args.clone()),
+            FbsimGameSubcommand::Benchmark(args) => match &args.command {
+                Some(FbsimGameBenchmarkSubcommand::Compare(compare_args)) => game_benchmark_compare(compare_args.clone(), seed),
+                None => game_benchmark(args.clone(), seed)
+            },
+            FbsimGameSubcommand::Coach(args) => game_coach(args.clone(), seed),
+            FbsimGameSubcommand::Decide(args) => game_decide(args.clone(), seed),
+            FbsimGameSubcommand::Sim(args) => game_sim(args.clone(), seed, wait),
+            FbsimGameSubcommand::Replay(args) => game_replay(args.clone()),
+            FbsimGameSubcommand::Series(args) => game_series(args.clone(), seed),
+            FbsimGameSubcommand::Winprob(args) => game_winprob(args.clone(), seed),
+            FbsimGameSubcommand::Context { command } => match command {
+                FbsimGameContextSubcommand::New(args) => context_new(args.clone()),
+                FbsimGameContextSubcommand::Set(args) => context_set(args.clone()),
+                FbsimGameContextSubcommand::Show(args) => context_show(args.clone())
+            },
             FbsimGameSubcommand::Drive { command } => match command {
-                FbsimGameDriveSubcommand::Sim(args) => drive_sim(args.clone())
+                FbsimGameDriveSubcommand::Benchmark(args) => drive_benchmark(args.clone(), seed),
+                FbsimGameDriveSubcommand::Sim(args) => drive_sim(args.clone(), seed, wait)
             },
             FbsimGameSubcommand::Play { command } => match command {
-                FbsimGamePlaySubcommand::Sim(args) => play_sim(args.clone()),
-                FbsimGamePlaySubcommand::Benchmark(args) => play_benchmark(args.clone()),
+                FbsimGamePlaySubcommand::Sim(args) => play_sim(args.clone(), seed, wait),
+                FbsimGamePlaySubcommand::Benchmark(args) => play_benchmark(args.clone(), seed),
             },
             FbsimGameSubcommand::Score { command } => match command {
-                FbsimGameScoreSubcommand::Sim(args) => final_score_sim(args.clone()),
-                FbsimGameScoreSubcommand::Benchmark(args) => final_score_sim_benchmark(args.clone())
+                FbsimGameScoreSubcommand::Sim(args) => final_score_sim(args.clone(), seed, wait),
+                FbsimGameScoreSubcommand::Benchmark(args) => final_score_sim_benchmark(args.clone(), seed)
             }
         },
         FbsimSubcommand::League { command } => match command {
-            FbsimLeagueSubcommand::Create(args) => create_league(args.clone()),
+            FbsimLeagueSubcommand::Create(args) => create_league(args.clone(), wait),
+            FbsimLeagueSubcommand::Convert(args) => convert_league(args.clone(), wait),
+            FbsimLeagueSubcommand::Undo(args) => undo_league(args.clone(), wait),
+            FbsimLeagueSubcommand::History(args) => league_history(args.clone(), wait),
             FbsimLeagueSubcommand::Team { command } => match command {
-                FbsimLeagueTeamSubcommand::Add(args) => add_team(args.clone()),
-                FbsimLeagueTeamSubcommand::Get(args) => get_team(args.clone()),
-                FbsimLeagueTeamSubcommand::List(args) => list_teams(args.clone()),
+                FbsimLeagueTeamSubcommand::Add(args) => add_team(args.clone(), wait),
+                FbsimLeagueTeamSubcommand::Get(args) => get_team(args.clone(), wait),
+                FbsimLeagueTeamSubcommand::List(args) => list_teams(args.clone(), wait),
                 FbsimLeagueTeamSubcommand::Stats{ command } => match command {
-                    FbsimLeagueTeamStatsSubcommand::Passing(args) => list_passing(args.clone()),
-                    FbsimLeagueTeamStatsSubcommand::Receiving(args) => list_receiving(args.clone()),
-                    FbsimLeagueTeamStatsSubcommand::Rushing(args) => list_rushing(args.clone())
+                    FbsimLeagueTeamStatsSubcommand::Passing(args) => list_passing(args.clone(), wait),
+                    FbsimLeagueTeamStatsSubcommand::Receiving(args) => list_receiving(args.clone(), wait),
+                    FbsimLeagueTeamStatsSubcommand::Rushing(args) => list_rushing(args.clone(), wait)
                 }
             },
             FbsimLeagueSubcommand::Season { command } => match command {
-                FbsimLeagueSeasonSubcommand::Add(args) => add_season(args.clone()),
-                FbsimLeagueSeasonSubcommand::Get(args) => get_season(args.clone()),
-                FbsimLeagueSeasonSubcommand::List(args) => list_seasons(args.clone()),
-                FbsimLeagueSeasonSubcommand::Sim(args) => sim_season(args.clone()),
-                FbsimLeagueSeasonSubcommand::Standings(args) => get_standings(args.clone()),
+                FbsimLeagueSeasonSubcommand::Add(args) => add_season(args.clone(), wait),
+                FbsimLeagueSeasonSubcommand::Get(args) => get_season(args.clone(), wait),
+                FbsimLeagueSeasonSubcommand::List(args) => list_seasons(args.clone(), wait),
+                FbsimLeagueSeasonSubcommand::Sim(args) => sim_season(args.clone(), seed, wait),
+                FbsimLeagueSeasonSubcommand::Standings(args) => get_standings(args.clone(), wait),
                 FbsimLeagueSeasonSubcommand::Conference{ command } => match command {
-                    FbsimLeagueSeasonConferenceSubcommand::Add(args) => add_conference(args.clone()),
-                    FbsimLeagueSeasonConferenceSubcommand::Get(args) => get_conference(args.clone()),
-                    FbsimLeagueSeasonConferenceSubcommand::List(args) => list_conferences(args.clone()),
+                    FbsimLeagueSeasonConferenceSubcommand::Add(args) => add_conference(args.clone(), wait),
+                    FbsimLeagueSeasonConferenceSubcommand::Get(args) => get_conference(args.clone(), wait),
+                    FbsimLeagueSeasonConferenceSubcommand::List(args) => list_conferences(args.clone(), wait),
                     FbsimLeagueSeasonConferenceSubcommand::Division{ command } => match command {
-                        FbsimLeagueSeasonConferenceDivisionSubcommand::Add(args) => add_division(args.clone()),
-                        FbsimLeagueSeasonConferenceDivisionSubcommand::Get(args) => get_division(args.clone()),
-                        FbsimLeagueSeasonConferenceDivisionSubcommand::List(args) => list_divisions(args.clone())
+                        FbsimLeagueSeasonConferenceDivisionSubcommand::Add(args) => add_division(args.clone(), wait),
+                        FbsimLeagueSeasonConferenceDivisionSubcommand::Get(args) => get_division(args.clone(), wait),
+                        FbsimLeagueSeasonConferenceDivisionSubcommand::List(args) => list_divisions(args.clone(), wait)
                     }
                 },
                 FbsimLeagueSeasonSubcommand::Team{ command } => match command {
-                    FbsimLeagueSeasonTeamSubcommand::Add(args) => add_season_team(args.clone()),
-                    FbsimLeagueSeasonTeamSubcommand::Assign(args) => assign_team(args.clone()),
-                    FbsimLeagueSeasonTeamSubcommand::Get(args) => get_season_team(args.clone()),
-                    FbsimLeagueSeasonTeamSubcommand::List(args) => list_season_teams(args.clone()),
+                    FbsimLeagueSeasonTeamSubcommand::Add(args) => add_season_team(args.clone(), wait),
+                    FbsimLeagueSeasonTeamSubcommand::Assign(args) => assign_team(args.clone(), wait),
+                    FbsimLeagueSeasonTeamSubcommand::Get(args) => get_season_team(args.clone(), wait),
+                    FbsimLeagueSeasonTeamSubcommand::List(args) => list_season_teams(args.clone(), wait),
                     FbsimLeagueSeasonTeamSubcommand::Stats{ command } => match command {
-                        FbsimLeagueSeasonTeamStatsSubcommand::Passing(args) => list_season_passing(args.clone()),
-                        FbsimLeagueSeasonTeamStatsSubcommand::Receiving(args) => list_season_receiving(args.clone()),
-                        FbsimLeagueSeasonTeamStatsSubcommand::Rushing(args) => list_season_rushing(args.clone())
+                        FbsimLeagueSeasonTeamStatsSubcommand::Passing(args) => list_season_passing(args.clone(), wait),
+                        FbsimLeagueSeasonTeamStatsSubcommand::Receiving(args) => list_season_receiving(args.clone(), wait),
+                        FbsimLeagueSeasonTeamStatsSubcommand::Rushing(args) => list_season_rushing(args.clone(), wait)
                     }
                 },
                 FbsimLeagueSeasonSubcommand::Playoffs{ command } => match command {
-                    FbsimLeagueSeasonPlayoffsSubcommand::Gen(args) => gen_playoffs(args.clone()),
-                    FbsimLeagueSeasonPlayoffsSubcommand::Get(args) => get_playoffs(args.clone()),
-                    FbsimLeagueSeasonPlayoffsSubcommand::Picture(args) => get_playoffs_picture(args.clone()),
-                    FbsimLeagueSeasonPlayoffsSubcommand::Sim(args) => sim_playoffs(args.clone()),
+                    FbsimLeagueSeasonPlayoffsSubcommand::Gen(args) => gen_playoffs(args.clone(), seed, wait),
+                    FbsimLeagueSeasonPlayoffsSubcommand::Get(args) => get_playoffs(args.clone(), wait),
+                    FbsimLeagueSeasonPlayoffsSubcommand::Picture(args) => get_playoffs_picture(args.clone(), wait),
+                    FbsimLeagueSeasonPlayoffsSubcommand::Sim(args) => sim_playoffs(args.clone(), seed, wait),
                     FbsimLeagueSeasonPlayoffsSubcommand::Round{ command } => match command {
-                        FbsimLeagueSeasonPlayoffsRoundSubcommand::Get(args) => get_playoffs_round(args.clone()),
-                        FbsimLeagueSeasonPlayoffsRoundSubcommand::Sim(args) => sim_playoffs_round(args.clone()),
+                        FbsimLeagueSeasonPlayoffsRoundSubcommand::Get(args) => get_playoffs_round(args.clone(), wait),
+                        FbsimLeagueSeasonPlayoffsRoundSubcommand::Sim(args) => sim_playoffs_round(args.clone(), seed, wait),
                         FbsimLeagueSeasonPlayoffsRoundSubcommand::Matchup{ command } => match command {
-                            FbsimLeagueSeasonPlayoffsRoundMatchupSubcommand::Get(args) => get_playoffs_matchup(args.clone()),
-                            FbsimLeagueSeasonPlayoffsRoundMatchupSubcommand::Sim(args) => sim_playoffs_matchup(args.clone())
+                            FbsimLeagueSeasonPlayoffsRoundMatchupSubcommand::Get(args) => get_playoffs_matchup(args.clone(), wait),
+                            FbsimLeagueSeasonPlayoffsRoundMatchupSubcommand::Sim(args) => sim_playoffs_matchup(args.clone(), seed, wait)
                         }
                     }
                 },
                 FbsimLeagueSeasonSubcommand::Schedule{ command } => match command {
-                    FbsimLeagueSeasonScheduleSubcommand::Gen(args) => generate_schedule(args.clone())
+                    FbsimLeagueSeasonScheduleSubcommand::Gen(args) => generate_schedule(args.clone(), seed, wait)
                 },
                 FbsimLeagueSeasonSubcommand::Week{ command } => match command {
-                    FbsimLeagueSeasonWeekSubcommand::Get(args) => get_season_week(args.clone()),
-                    FbsimLeagueSeasonWeekSubcommand::List(args) => list_season_weeks(args.clone()),
-                    FbsimLeagueSeasonWeekSubcommand::Sim(args) => sim_season_week(args.clone()),
+                    FbsimLeagueSeasonWeekSubcommand::Get(args) => get_season_week(args.clone(), wait),
+                    FbsimLeagueSeasonWeekSubcommand::List(args) => list_season_weeks(args.clone(), wait),
+                    FbsimLeagueSeasonWeekSubcommand::Sim(args) => sim_season_week(args.clone(), seed, wait),
                     FbsimLeagueSeasonWeekSubcommand::Matchup{ command } => match command {
-                        FbsimLeagueSeasonWeekMatchupSubcommand::Get(args) => get_matchup(args.clone()),
-                        FbsimLeagueSeasonWeekMatchupSubcommand::Sim(args) => sim_matchup(args.clone()),
+                        FbsimLeagueSeasonWeekMatchupSubcommand::Get(args) => get_matchup(args.clone(), wait),
+                        FbsimLeagueSeasonWeekMatchupSubcommand::Sim(args) => sim_matchup(args.clone(), seed, wait),
                         FbsimLeagueSeasonWeekMatchupSubcommand::Play{ command } => match command {
-                            FbsimLeagueSeasonWeekMatchupPlaySubcommand::Sim(args) => sim_play(args.clone())
+                            FbsimLeagueSeasonWeekMatchupPlaySubcommand::Sim(args) => sim_play(args.clone(), seed, wait)
                         }
                     }
                 }
             }
+        },
+        FbsimSubcommand::Team { command } => match command {
+            FbsimTeamSubcommand::Compare(args) => compare_teams(args.clone(), seed),
+            FbsimTeamSubcommand::Gen(args) => gen_teams(args.clone(), seed),
+            FbsimTeamSubcommand::Validate(args) => validate_teams(args.clone())
         }
     };
     match command_res {
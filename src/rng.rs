@@ -0,0 +1,17 @@
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+
+/// Instantiate the RNG used by a simulation command
+///
+/// Seeds the RNG from the given seed if one was passed via the global
+/// `--seed` argument so that the simulation can be reproduced exactly,
+/// otherwise seeds it from the thread-local RNG
+pub fn new_rng(seed: Option<u64>) -> Result<StdRng, String> {
+    match seed {
+        Some(seed) => Ok(StdRng::seed_from_u64(seed)),
+        None => match StdRng::from_rng(rand::thread_rng()) {
+            Ok(rng) => Ok(rng),
+            Err(error) => Err(format!("Failed to instantiate rng: {}", error)),
+        },
+    }
+}
@@ -0,0 +1,4 @@
+pub mod compare;
+pub mod gen;
+pub mod load;
+pub mod validate;
\ No newline at end of file
@@ -0,0 +1,180 @@
+use indicatif::ProgressBar;
+use rand::Rng;
+
+use fbsim_core::game::context::{GameContext, GameContextBuilder};
+use fbsim_core::game::play::{GameSimulator, PlaySimulatable};
+use fbsim_core::team::FootballTeam;
+
+use crate::benchmark::tabulate;
+use crate::cli::team::FbsimTeamCompareArgs;
+use crate::game::winprob::{confidence_z, wilson_interval, WinProbability};
+use crate::rng::new_rng;
+use crate::team::load::load_team;
+
+/// The number of biggest mismatches to display
+const NUM_MISMATCHES: usize = 3;
+
+/// An offensive attribute, the defensive attribute countering it and their skill levels
+struct Matchup {
+    offense: &'static str,
+    defense: &'static str,
+    offense_skill: u32,
+    defense_skill: u32
+}
+
+/// Pair each offensive attribute of a team with its counter on the opposing defense
+fn unit_matchups(offense: &FootballTeam, defense: &FootballTeam) -> Vec<Matchup> {
+    let o = offense.offense();
+    let d = defense.defense();
+    [
+        ("Passing", "Pass Defense", o.passing(), d.pass_defense()),
+        ("Receiving", "Coverage", o.receiving(), d.coverage()),
+        ("Rushing", "Rush Defense", o.rushing(), d.rush_defense()),
+        ("Scrambling", "Rush Defense", o.scrambling(), d.rush_defense()),
+        ("Blocking", "Blitzing", o.blocking(), d.blitzing()),
+        ("Turnovers", "Turnovers", o.turnovers(), d.turnovers()),
+        ("Kick Return Defense", "Kick Returning", o.kick_return_defense(), d.kick_returning())
+    ]
+        .into_iter()
+        .map(|(offense, defense, offense_skill, defense_skill)| Matchup{
+            offense,
+            defense,
+            offense_skill,
+            defense_skill
+        })
+        .collect()
+}
+
+/// Format the matchups of one team's offense against the other's defense
+fn matchup_lines(offense: &FootballTeam, defense: &FootballTeam, matchups: &[Matchup]) -> String {
+    let mut lines = format!(
+        "{} Offense\tSkill\t{} Defense\tSkill\tEdge",
+        offense.short_name(), defense.short_name()
+    );
+    for m in matchups.iter() {
+        let line = format!(
+            "{}\t{}\t{}\t{}\t{:+}",
+            m.offense, m.offense_skill, m.defense, m.defense_skill,
+            m.offense_skill as i32 - m.defense_skill as i32
+        );
+        lines = lines + "\n" + &line;
+    }
+    lines
+}
+
+pub fn compare_teams(args: FbsimTeamCompareArgs, seed: Option<u64>) -> Result<(), String> {
+    // Load both teams from their files
+    let team_a: FootballTeam = match load_team(&args.team_a) {
+        Ok(team) => team,
+        Err(e) => return Err(format!("Error loading team: {}", e)),
+    };
+    let team_b: FootballTeam = match load_team(&args.team_b) {
+        Ok(team) => team,
+        Err(e) => return Err(format!("Error loading team: {}", e)),
+    };
+    let (a, b) = (team_a.short_name(), team_b.short_name());
+
+    // Display the overall ratings of each team
+    let overall_lines = format!(
+        "Team\tOffense\tDefense\n{} ({})\t{}\t{}\n{} ({})\t{}\t{}",
+        team_a.name(), a, team_a.offense().overall(), team_a.defense().overall(),
+        team_b.name(), b, team_b.offense().overall(), team_b.defense().overall()
+    );
+    println!();
+    println!("Overall ratings:");
+    println!("{}", tabulate(&overall_lines));
+
+    // Display each offense against the opposing defense
+    let a_matchups = unit_matchups(&team_a, &team_b);
+    let b_matchups = unit_matchups(&team_b, &team_a);
+    println!();
+    println!("{} offense vs {} defense:", a, b);
+    println!("{}", tabulate(&matchup_lines(&team_a, &team_b, &a_matchups)));
+    println!();
+    println!("{} offense vs {} defense:", b, a);
+    println!("{}", tabulate(&matchup_lines(&team_b, &team_a, &b_matchups)));
+
+    // Display the kicking and coach attributes side by side
+    let (ao, bo) = (team_a.offense(), team_b.offense());
+    let (ac, bc) = (team_a.coach(), team_b.coach());
+    let side_by_side = [
+        ("Field Goals", ao.field_goals(), bo.field_goals()),
+        ("Punting", ao.punting(), bo.punting()),
+        ("Kickoffs", ao.kickoffs(), bo.kickoffs()),
+        ("Coach Risk Taking", ac.risk_taking(), bc.risk_taking()),
+        ("Coach Run Pass", ac.run_pass(), bc.run_pass()),
+        ("Coach Up Tempo", ac.up_tempo(), bc.up_tempo())
+    ];
+    let mut side_by_side_lines = format!("Attribute\t{}\t{}\tDifference", a, b);
+    for (attribute, a_skill, b_skill) in side_by_side.iter() {
+        let line = format!("{}\t{}\t{}\t{:+}", attribute, a_skill, b_skill, *a_skill as i32 - *b_skill as i32);
+        side_by_side_lines = side_by_side_lines + "\n" + &line;
+    }
+    println!();
+    println!("Kicking and coaching:");
+    println!("{}", tabulate(&side_by_side_lines));
+
+    // Display the biggest mismatches in either direction
+    let mut mismatches: Vec<(&str, &str, &Matchup)> = a_matchups.iter().map(|m| (a, b, m))
+        .chain(b_matchups.iter().map(|m| (b, a, m)))
+        .collect();
+    mismatches.sort_by_key(|(_, _, m)| -(m.offense_skill as i32 - m.defense_skill as i32).abs());
+    println!();
+    println!("Biggest mismatches:");
+    for (offense, defense, m) in mismatches.into_iter().take(NUM_MISMATCHES) {
+        let edge = m.offense_skill as i32 - m.defense_skill as i32;
+        let favored = if edge >= 0 { offense } else { defense };
+        println!(
+            "{} {} ({}) vs {} {} ({}): {:+}, favors {}",
+            offense, m.offense, m.offense_skill, defense, m.defense, m.defense_skill, edge, favored
+        );
+    }
+
+    // Load the preview arguments
+    let num_games: usize = args.games.unwrap_or(1000);
+    if num_games == 0 {
+        return Err(String::from("Number of games must be greater than 0"));
+    }
+    let neutral_site: bool = args.neutral_site.unwrap_or(false);
+
+    // Simulate the matchup with the first team at home
+    let game_sim = GameSimulator::new();
+    let mut rng = new_rng(seed)?;
+    let progress_bar = ProgressBar::new(num_games as u64);
+    let mut win_prob = WinProbability::default();
+    for _ in 0..num_games {
+        let home_opening_kickoff: bool = rng.gen::<bool>();
+        let context: GameContext = GameContextBuilder::new()
+            .home_team_short(a)
+            .away_team_short(b)
+            .home_possession(!home_opening_kickoff)
+            .home_positive_direction(!home_opening_kickoff)
+            .home_opening_kickoff(home_opening_kickoff)
+            .neutral_site(neutral_site)
+            .build()
+            .unwrap();
+        let final_context = match game_sim.sim(&team_a, &team_b, context, &mut rng) {
+            Ok((_game, c)) => c,
+            Err(e) => return Err(format!("Error simulating game: {}", e))
+        };
+        win_prob.record(&final_context);
+        progress_bar.inc(1);
+    }
+    progress_bar.finish();
+
+    // Display the projected score and win probability
+    let z = confidence_z(0.95)?;
+    let (a_low, a_high) = wilson_interval(win_prob.home_wins(), num_games, z);
+    let (b_low, b_high) = wilson_interval(win_prob.away_wins(), num_games, z);
+    let projection_lines = format!(
+        "Team\tProjected Score\tWin Probability\t95% CI\n{}\t{:.1}\t{:.2}%\t[{:.2}%, {:.2}%]\n{}\t{:.1}\t{:.2}%\t[{:.2}%, {:.2}%]",
+        a, win_prob.mean_home_score(),
+        win_prob.home_wins() as f64 * 100_f64 / num_games as f64, a_low * 100_f64, a_high * 100_f64,
+        b, win_prob.mean_away_score(),
+        win_prob.away_wins() as f64 * 100_f64 / num_games as f64, b_low * 100_f64, b_high * 100_f64
+    );
+    println!();
+    println!("Matchup preview ({} @ {}, {} games):", b, a, num_games);
+    println!("{}", tabulate(&projection_lines));
+    Ok(())
+}
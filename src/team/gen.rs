@@ -0,0 +1,265 @@
+use std::fs;
+use std::path::Path;
+
+use rand::Rng;
+use rand::seq::SliceRandom;
+
+use fbsim_core::team::FootballTeam;
+use fbsim_core::team::coach::FootballTeamCoachBuilder;
+use fbsim_core::team::defense::FootballTeamDefenseBuilder;
+use fbsim_core::team::offense::FootballTeamOffenseBuilder;
+
+use crate::cli::team::FbsimTeamGenArgs;
+use crate::rng::new_rng;
+
+use serde_json;
+
+/// The longest allowed team short name, in characters
+const MAX_SHORT_NAME_LEN: usize = 4;
+
+/// The most teams which can be numbered while keeping at least one
+/// character of the given short name within the short name limit
+const MAX_NUMBERED_TEAMS: usize = 999;
+
+/// The cities and short names used to generate random team names
+const CITIES: [(&str, &str); 32] = [
+    ("Ashford", "ASH"),
+    ("Bayport", "BAY"),
+    ("Brookhaven", "BRK"),
+    ("Cedar Falls", "CDF"),
+    ("Clearwater", "CLW"),
+    ("Crestview", "CRV"),
+    ("Dunmore", "DUN"),
+    ("Eastbrook", "EBK"),
+    ("Fairhaven", "FHV"),
+    ("Glenwood", "GLW"),
+    ("Granite City", "GRC"),
+    ("Harbor Point", "HBP"),
+    ("Highland", "HLD"),
+    ("Ironton", "IRT"),
+    ("Kingsport", "KPT"),
+    ("Lakeside", "LKS"),
+    ("Maple Ridge", "MPR"),
+    ("Millbrook", "MLB"),
+    ("New Salem", "NSL"),
+    ("Northfield", "NFD"),
+    ("Oak Harbor", "OKH"),
+    ("Pine Bluff", "PNB"),
+    ("Port Royal", "PRY"),
+    ("Redstone", "RDS"),
+    ("Riverside", "RVS"),
+    ("Silver Lake", "SLK"),
+    ("Springdale", "SPD"),
+    ("Stonebridge", "STB"),
+    ("Summit", "SUM"),
+    ("Twin Forks", "TWF"),
+    ("Westport", "WPT"),
+    ("Willow Creek", "WLC")
+];
+
+/// The mascots used to generate random team names
+const MASCOTS: [&str; 32] = [
+    "Anchors",
+    "Badgers",
+    "Bison",
+    "Blizzard",
+    "Bulldogs",
+    "Comets",
+    "Condors",
+    "Coyotes",
+    "Cyclones",
+    "Falcons",
+    "Foxes",
+    "Grizzlies",
+    "Hawks",
+    "Hornets",
+    "Huskies",
+    "Jackals",
+    "Knights",
+    "Lumberjacks",
+    "Mustangs",
+    "Otters",
+    "Outlaws",
+    "Panthers",
+    "Pioneers",
+    "Raptors",
+    "Rattlers",
+    "Rockets",
+    "Stallions",
+    "Thunder",
+    "Titans",
+    "Vipers",
+    "Voyagers",
+    "Wolves"
+];
+
+/// Randomize skill levels around a target overall
+///
+/// Each skill level is moved by at most the jitter, then random skill levels
+/// are nudged until their mean is exactly the target overall again.
+fn jitter_skills<const N: usize>(overall: u32, jitter: u32, rng: &mut impl Rng) -> [u32; N] {
+    let mut skills: [u32; N] = [overall; N];
+    for skill in skills.iter_mut() {
+        let offset: i64 = rng.gen_range(-(jitter as i64)..=(jitter as i64));
+        *skill = (overall as i64 + offset).clamp(0, 100) as u32;
+    }
+    let target: u32 = overall * N as u32;
+    loop {
+        let total: u32 = skills.iter().sum();
+        let i = rng.gen_range(0..N);
+        if total < target && skills[i] < 100 {
+            skills[i] += 1;
+        } else if total > target && skills[i] > 0 {
+            skills[i] -= 1;
+        } else if total == target {
+            break;
+        }
+    }
+    skills
+}
+
+/// Generate a team with the given overalls, optionally jittering its skill levels
+fn gen_team(name: &str, short_name: &str, args: &FbsimTeamGenArgs, jitter: u32, rng: &mut impl Rng) -> Result<FootballTeam, String> {
+    let team = FootballTeam::from_overalls(name, short_name, args.overall_offense, args.overall_defense)?;
+    if jitter == 0 {
+        return Ok(team);
+    }
+
+    // Rebuild the coach, offense and defense with jittered skill levels
+    let c = jitter_skills::<3>(50, jitter, rng);
+    let coach = FootballTeamCoachBuilder::new()
+        .risk_taking(c[0])
+        .run_pass(c[1])
+        .up_tempo(c[2])
+        .build()?;
+    let o = jitter_skills::<10>(args.overall_offense, jitter, rng);
+    let offense = FootballTeamOffenseBuilder::new()
+        .passing(o[0])
+        .blocking(o[1])
+        .rushing(o[2])
+        .receiving(o[3])
+        .scrambling(o[4])
+        .turnovers(o[5])
+        .field_goals(o[6])
+        .punting(o[7])
+        .kickoffs(o[8])
+        .kick_return_defense(o[9])
+        .build()?;
+    let d = jitter_skills::<6>(args.overall_defense, jitter, rng);
+    let defense = FootballTeamDefenseBuilder::new()
+        .blitzing(d[0])
+        .rush_defense(d[1])
+        .pass_defense(d[2])
+        .coverage(d[3])
+        .turnovers(d[4])
+        .kick_returning(d[5])
+        .build()?;
+    Ok(FootballTeam::from_properties(name, short_name, coach, offense, defense))
+}
+
+/// Generate the names and short names of the teams
+fn gen_names(args: &FbsimTeamGenArgs, count: usize, rng: &mut impl Rng) -> Result<Vec<(String, String)>, String> {
+    // Number the given name and short name if generating many teams
+    if let (Some(name), Some(short)) = (&args.name, &args.short) {
+        if short.chars().count() > MAX_SHORT_NAME_LEN {
+            return Err(format!("Team short name is longer than {} characters: {}", MAX_SHORT_NAME_LEN, short));
+        }
+        if count == 1 {
+            return Ok(vec![(name.clone(), short.clone())]);
+        }
+        if count > MAX_NUMBERED_TEAMS {
+            return Err(format!("Cannot generate more than {} numbered teams", MAX_NUMBERED_TEAMS));
+        }
+        let width = count.to_string().len();
+        let prefix: String = short.chars().take(MAX_SHORT_NAME_LEN.saturating_sub(width)).collect();
+        return Ok(
+            (1..=count)
+                .map(|i| (format!("{} {}", name, i), format!("{}{:0width$}", prefix, i, width = width)))
+                .collect()
+        );
+    }
+
+    // Otherwise pair random cities with random mascots
+    if count > CITIES.len() {
+        return Err(
+            format!(
+                "Cannot generate more than {} random team names, pass --name and --short instead",
+                CITIES.len()
+            )
+        );
+    }
+    let mut cities = CITIES.to_vec();
+    let mut mascots = MASCOTS.to_vec();
+    cities.shuffle(rng);
+    mascots.shuffle(rng);
+    Ok(
+        cities.into_iter()
+            .zip(mascots)
+            .take(count)
+            .map(|((city, short), mascot)| (format!("{} {}", city, mascot), String::from(short)))
+            .collect()
+    )
+}
+
+pub fn gen_teams(args: FbsimTeamGenArgs, seed: Option<u64>) -> Result<(), String> {
+    // Load the generator arguments
+    let count: usize = args.count.unwrap_or(1);
+    if count == 0 {
+        return Err(String::from("Number of teams must be greater than 0"));
+    }
+    if count > 1 && args.output_file.is_some() {
+        return Err(String::from("Cannot write many teams to a single file, pass --dir instead"));
+    }
+    let jitter: u32 = args.jitter.unwrap_or(0);
+
+    // Generate the teams
+    let mut rng = new_rng(seed)?;
+    let names = gen_names(&args, count, &mut rng)?;
+    let mut teams: Vec<FootballTeam> = Vec::new();
+    for (name, short_name) in names.iter() {
+        match gen_team(name, short_name, &args, jitter, &mut rng) {
+            Ok(team) => teams.push(team),
+            Err(e) => return Err(format!("Error generating team: {}", e))
+        }
+    }
+
+    // Write each team to the output directory
+    if let Some(dir) = &args.output_dir {
+        if let Err(e) = fs::create_dir_all(dir) {
+            return Err(format!("Error creating team directory: {}", e));
+        }
+        for team in teams.iter() {
+            let team_str = match serde_json::to_string_pretty(team) {
+                Ok(s) => s,
+                Err(e) => return Err(format!("Error serializing team: {}", e))
+            };
+            let path = Path::new(dir).join(format!("{}.json", team.short_name().to_lowercase()));
+            if let Err(e) = fs::write(&path, team_str) {
+                return Err(format!("Error writing team file: {}", e));
+            }
+        }
+        println!("{} teams generated in {}", teams.len(), dir);
+        return Ok(());
+    }
+
+    // Otherwise write the team to its file, or the teams to stdout
+    let teams_str_res = if teams.len() == 1 {
+        serde_json::to_string_pretty(&teams[0])
+    } else {
+        serde_json::to_string_pretty(&teams)
+    };
+    let teams_str = match teams_str_res {
+        Ok(s) => s,
+        Err(e) => return Err(format!("Error serializing team: {}", e))
+    };
+    match &args.output_file {
+        Some(file) => {
+            if let Err(e) = fs::write(file, teams_str) {
+                return Err(format!("Error writing team file: {}", e));
+            }
+            println!("{} generated at {}", teams[0].name(), file);
+        },
+        None => println!("{}", teams_str)
+    }
+    Ok(())
+}
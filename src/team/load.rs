@@ -0,0 +1,175 @@
+use std::fs;
+use std::path::Path;
+
+use fbsim_core::league::League;
+use fbsim_core::team::FootballTeam;
+
+use crate::cli::game::FbsimGameTeamsArgs;
+use crate::league::lock::LockMode;
+use crate::league::store::{open_league, LeagueScope};
+
+use serde::de::DeserializeOwned;
+use serde_json;
+use serde_norway;
+use toml;
+
+/// The file formats a team can be loaded from
+#[derive(Clone, Copy, PartialEq)]
+pub enum TeamFormat {
+    Json,
+    Toml,
+    Yaml
+}
+
+impl TeamFormat {
+    /// Pick the format of a team file from its extension, defaulting to JSON
+    pub fn from_path(path: &str) -> TeamFormat {
+        let extension = Path::new(path)
+            .extension()
+            .and_then(|e| e.to_str())
+            .map(|e| e.to_lowercase());
+        match extension.as_deref() {
+            Some("toml") => TeamFormat::Toml,
+            Some("yaml") | Some("yml") => TeamFormat::Yaml,
+            _ => TeamFormat::Json
+        }
+    }
+
+    /// The display name of the format
+    pub fn name(&self) -> &'static str {
+        match self {
+            TeamFormat::Json => "JSON",
+            TeamFormat::Toml => "TOML",
+            TeamFormat::Yaml => "YAML"
+        }
+    }
+}
+
+/// An error parsing a team file, with its line and column
+pub struct TeamParseError {
+    pub line: usize,
+    pub column: usize,
+    pub message: String
+}
+
+/// The line and column of a byte offset into a text
+fn line_column(text: &str, offset: usize) -> (usize, usize) {
+    let mut offset = offset.min(text.len());
+    while !text.is_char_boundary(offset) {
+        offset -= 1;
+    }
+    let before = &text[..offset];
+    let line = before.matches('\n').count() + 1;
+    let column = before.rsplit('\n').next().unwrap_or("").chars().count() + 1;
+    (line, column)
+}
+
+/// Strip the trailing position from a parser error message
+fn strip_position(message: &str) -> String {
+    match message.split_once(" at line ") {
+        Some((m, _)) => String::from(m),
+        None => String::from(message)
+    }
+}
+
+/// Parse the contents of a team file in the given format
+pub fn parse_team<T: DeserializeOwned>(text: &str, format: TeamFormat) -> Result<T, TeamParseError> {
+    match format {
+        TeamFormat::Json => serde_json::from_str(text).map_err(|e| TeamParseError{
+            line: e.line(),
+            column: e.column(),
+            message: strip_position(&e.to_string())
+        }),
+        TeamFormat::Toml => toml::from_str(text).map_err(|e| {
+            let (line, column) = match e.span() {
+                Some(span) => line_column(text, span.start),
+                None => (1, 1)
+            };
+            TeamParseError{
+                line,
+                column,
+                message: String::from(e.message())
+            }
+        }),
+        TeamFormat::Yaml => serde_norway::from_str(text).map_err(|e| {
+            let (line, column) = match e.location() {
+                Some(location) => (location.line(), location.column()),
+                None => (1, 1)
+            };
+            TeamParseError{
+                line,
+                column,
+                message: strip_position(&e.to_string())
+            }
+        })
+    }
+}
+
+/// Load a team from a JSON, TOML or YAML file, picking the format from its extension
+pub fn load_team(path: &str) -> Result<FootballTeam, String> {
+    let team_file = match fs::read_to_string(path) {
+        Ok(file) => file,
+        Err(e) => return Err(format!("Error reading {}: {}", path, e)),
+    };
+    match parse_team(&team_file, TeamFormat::from_path(path)) {
+        Ok(team) => Ok(team),
+        Err(e) => Err(format!("{}:{}:{}: {}", path, e.line, e.column, e.message))
+    }
+}
+
+/// Load a team by ID from a season of a league, the current season if no
+/// year is given
+fn load_league_team(league: &League, year: Option<usize>, id: usize) -> Result<FootballTeam, String> {
+    let season = match year {
+        Some(y) => match league.season(y) {
+            Some(season) => season,
+            None => return Err(format!("No season found with year: {}", y)),
+        },
+        None => match league.current_season() {
+            Some(season) => season,
+            None => return Err(String::from("No current season found")),
+        }
+    };
+    match season.team(id) {
+        Some(team) => Ok(team.clone()),
+        None => Err(format!("No team found in season {} with ID: {}", season.year(), id))
+    }
+}
+
+/// Load the home and away teams of a game, each from its file or by ID from
+/// a season of the league
+///
+/// The league is locked for reading only while it is loaded.
+pub fn load_game_teams(args: &FbsimGameTeamsArgs, wait: bool) -> Result<(FootballTeam, FootballTeam), String> {
+    // Lock the league and load it if any team is taken from it, only loading
+    // one season if both teams are taken from the same season
+    let home_year = args.home_year.or(args.year);
+    let away_year = args.away_year.or(args.year);
+    let league: Option<League> = match &args.league {
+        Some(path) => {
+            let scope = if home_year == away_year { LeagueScope::Season(home_year) } else { LeagueScope::All };
+            Some(open_league(path, &scope, LockMode::Shared, wait)?.1)
+        },
+        None => None
+    };
+
+    // Load each team from its file or the league
+    let mut teams: Vec<FootballTeam> = Vec::new();
+    for (side, path, id, year) in [
+        ("home", &args.home, args.home_id, home_year),
+        ("away", &args.away, args.away_id, away_year)
+    ] {
+        let team_res = match (path, id, &league) {
+            (Some(p), _, _) => load_team(p),
+            (None, Some(i), Some(l)) => load_league_team(l, year, i),
+            _ => Err(String::from("No team file or league team ID given"))
+        };
+        match team_res {
+            Ok(team) => teams.push(team),
+            Err(e) => return Err(format!("Error loading {} team: {}", side, e)),
+        }
+    }
+    let away_team = teams.pop().unwrap();
+    let home_team = teams.pop().unwrap();
+    Ok((home_team, away_team))
+}
@@ -0,0 +1,492 @@
+use std::fs;
+use std::collections::HashMap;
+
+use fbsim_core::team::FootballTeam;
+
+use crate::cli::team::FbsimTeamValidateArgs;
+use crate::team::load::{parse_team, TeamFormat};
+
+use serde_json::{self, Map, Value};
+
+/// The longest allowed team name, in characters
+const MAX_NAME_LEN: usize = 64;
+
+/// The longest allowed team short name, in characters
+const MAX_SHORT_NAME_LEN: usize = 4;
+
+/// The string properties of a team
+const TEAM_STRING_KEYS: [&str; 2] = ["name", "short_name"];
+
+/// The skill level groups of a team and the skill levels in each
+const TEAM_SKILL_GROUPS: [(&str, &[&str]); 3] = [
+    ("coach", &["risk_taking", "run_pass", "up_tempo"]),
+    ("offense", &[
+        "passing",
+        "blocking",
+        "rushing",
+        "receiving",
+        "scrambling",
+        "turnovers",
+        "field_goals",
+        "punting",
+        "kickoffs",
+        "kick_return_defense"
+    ]),
+    ("defense", &[
+        "blitzing",
+        "rush_defense",
+        "pass_defense",
+        "coverage",
+        "turnovers",
+        "kick_returning"
+    ])
+];
+
+/// A problem found in a team file, with its line and column
+struct TeamIssue {
+    line: usize,
+    column: usize,
+    message: String
+}
+
+/// Join a key onto the dotted path of its parent
+fn key_path(parent: &str, key: &str) -> String {
+    if parent.is_empty() {
+        String::from(key)
+    } else {
+        format!("{}.{}", parent, key)
+    }
+}
+
+/// Index the line and column of every key in a team file
+///
+/// Keys are indexed by their dotted path from the root, e.g.
+/// `offense.passing`.
+fn key_positions(text: &str, format: TeamFormat) -> HashMap<String, (usize, usize)> {
+    match format {
+        TeamFormat::Json => json_key_positions(text),
+        TeamFormat::Toml => toml_key_positions(text),
+        TeamFormat::Yaml => yaml_key_positions(text)
+    }
+}
+
+/// Index the line and column of every key in a TOML document
+fn toml_key_positions(text: &str) -> HashMap<String, (usize, usize)> {
+    let mut positions: HashMap<String, (usize, usize)> = HashMap::new();
+    let mut table = String::new();
+    for (i, line) in text.lines().enumerate() {
+        let trimmed = line.trim_start();
+        let column = line.len() - trimmed.len() + 1;
+        if trimmed.starts_with('#') {
+            continue;
+        }
+        if let Some(header) = trimmed.strip_prefix('[') {
+            // A table header sets the parent of the following keys
+            table = header.split(']').next().unwrap_or("").trim().replace(['"', ' '], "");
+            positions.insert(table.clone(), (i + 1, column));
+        } else if let Some((key, _)) = trimmed.split_once('=') {
+            let key = key.trim().replace(['"', ' '], "");
+            positions.insert(key_path(&table, &key), (i + 1, column));
+        }
+    }
+    positions
+}
+
+/// Index the line and column of every key in a block-style YAML document
+fn yaml_key_positions(text: &str) -> HashMap<String, (usize, usize)> {
+    let mut positions: HashMap<String, (usize, usize)> = HashMap::new();
+    let mut parents: Vec<(usize, String)> = Vec::new();
+    for (i, line) in text.lines().enumerate() {
+        let trimmed = line.trim_start();
+        let indent = line.len() - trimmed.len();
+        if trimmed.is_empty() || trimmed.starts_with('#') || trimmed.starts_with('-') {
+            continue;
+        }
+        if let Some((key, _)) = trimmed.split_once(':') {
+            // Keys are nested under the closest less-indented key
+            while parents.last().is_some_and(|(parent_indent, _)| *parent_indent >= indent) {
+                parents.pop();
+            }
+            let parent = parents.last().map(|(_, p)| p.as_str()).unwrap_or("");
+            let path = key_path(parent, key.trim().trim_matches(['"', '\'']));
+            positions.insert(path.clone(), (i + 1, indent + 1));
+            parents.push((indent, path));
+        }
+    }
+    positions
+}
+
+/// Index the line and column of every object key in a JSON document
+fn json_key_positions(text: &str) -> HashMap<String, (usize, usize)> {
+    let mut positions: HashMap<String, (usize, usize)> = HashMap::new();
+    let mut containers: Vec<(String, bool)> = Vec::new();
+    let mut last_key: Option<String> = None;
+    let mut expect_key = false;
+    let (mut line, mut column) = (1, 0);
+    let mut chars = text.chars();
+    while let Some(c) = chars.next() {
+        column += 1;
+        match c {
+            '\n' => {
+                line += 1;
+                column = 0;
+            },
+            '{' | '[' => {
+                // A container takes the path of its key, or of its parent
+                let path = match last_key.take() {
+                    Some(k) => k,
+                    None => containers.last().map(|(p, _)| p.clone()).unwrap_or_default()
+                };
+                containers.push((path, c == '{'));
+                expect_key = c == '{';
+            },
+            '}' | ']' => {
+                containers.pop();
+                last_key = None;
+            },
+            ',' => {
+                expect_key = containers.last().is_some_and(|(_, is_object)| *is_object);
+                last_key = None;
+            },
+            '"' => {
+                // Read the string, skipping escaped characters
+                let start = (line, column);
+                let mut string = String::new();
+                while let Some(s) = chars.next() {
+                    column += 1;
+                    match s {
+                        '"' => break,
+                        '\\' => {
+                            if let Some(escaped) = chars.next() {
+                                column += 1;
+                                string.push(escaped);
+                            }
+                        },
+                        '\n' => {
+                            line += 1;
+                            column = 0;
+                        },
+                        _ => string.push(s)
+                    }
+                }
+                if expect_key {
+                    let parent = containers.last().map(|(p, _)| p.as_str()).unwrap_or("");
+                    let path = key_path(parent, &string);
+                    positions.insert(path.clone(), start);
+                    last_key = Some(path);
+                    expect_key = false;
+                }
+            },
+            _ => ()
+        }
+    }
+    positions
+}
+
+/// Check the keys of a team file object against the expected keys
+fn check_keys(
+    object: &Map<String, Value>,
+    parent: &str,
+    expected: &[&str],
+    positions: &HashMap<String, (usize, usize)>,
+    issues: &mut Vec<TeamIssue>
+) {
+    let path = |key: &str| key_path(parent, key);
+    let (parent_line, parent_column) = positions.get(parent).copied().unwrap_or((1, 1));
+    for key in expected.iter() {
+        if !object.contains_key(*key) {
+            issues.push(
+                TeamIssue{
+                    line: parent_line,
+                    column: parent_column,
+                    message: format!("Missing field: {}", path(key))
+                }
+            );
+        }
+    }
+    for key in object.keys() {
+        if !expected.contains(&key.as_str()) {
+            let (line, column) = positions.get(&path(key)).copied().unwrap_or((parent_line, parent_column));
+            issues.push(
+                TeamIssue{
+                    line,
+                    column,
+                    message: format!("Unknown key: {}", path(key))
+                }
+            );
+        }
+    }
+}
+
+/// Check a team file for problems, returning its short name if it has one
+fn check_team(text: &str, format: TeamFormat, issues: &mut Vec<TeamIssue>) -> Option<(String, usize, usize)> {
+    // Parse the team file in its format
+    let value: Value = match parse_team(text, format) {
+        Ok(v) => v,
+        Err(e) => {
+            issues.push(
+                TeamIssue{
+                    line: e.line,
+                    column: e.column,
+                    message: format!("Invalid {}: {}", format.name(), e.message)
+                }
+            );
+            return None;
+        }
+    };
+    let team = match value.as_object() {
+        Some(t) => t,
+        None => {
+            issues.push(
+                TeamIssue{
+                    line: 1,
+                    column: 1,
+                    message: String::from("Team must be an object")
+                }
+            );
+            return None;
+        }
+    };
+    let positions = key_positions(text, format);
+    let position = |path: &str| positions.get(path).copied().unwrap_or((1, 1));
+
+    // Check the top-level keys
+    let mut top_level_keys: Vec<&str> = TEAM_STRING_KEYS.to_vec();
+    top_level_keys.extend(TEAM_SKILL_GROUPS.iter().map(|(group, _)| *group));
+    check_keys(team, "", &top_level_keys, &positions, issues);
+
+    // Check the name and short name
+    let mut short_name: Option<(String, usize, usize)> = None;
+    for (key, max_len) in [("name", MAX_NAME_LEN), ("short_name", MAX_SHORT_NAME_LEN)] {
+        let (line, column) = position(key);
+        match team.get(key) {
+            Some(Value::String(s)) => {
+                if s.len() > max_len {
+                    issues.push(
+                        TeamIssue{
+                            line,
+                            column,
+                            message: format!("{} is longer than {} characters: {}", key, max_len, s)
+                        }
+                    );
+                }
+                if key == "short_name" {
+                    short_name = Some((s.clone(), line, column));
+                }
+            },
+            Some(v) => issues.push(
+                TeamIssue{
+                    line,
+                    column,
+                    message: format!("{} must be a string: {}", key, v)
+                }
+            ),
+            None => ()
+        }
+    }
+
+    // Check each skill level group
+    for (group, skills) in TEAM_SKILL_GROUPS.iter() {
+        let (line, column) = position(group);
+        let group_object = match team.get(*group) {
+            Some(Value::Object(o)) => o,
+            Some(v) => {
+                issues.push(
+                    TeamIssue{
+                        line,
+                        column,
+                        message: format!("{} must be an object: {}", group, v)
+                    }
+                );
+                continue;
+            },
+            None => continue
+        };
+        check_keys(group_object, group, skills, &positions, issues);
+        for skill in skills.iter() {
+            let path = format!("{}.{}", group, skill);
+            let (line, column) = position(&path);
+            match group_object.get(*skill) {
+                Some(Value::Number(n)) if n.as_u64().is_some_and(|x| x <= 100) => (),
+                Some(Value::Number(n)) => issues.push(
+                    TeamIssue{
+                        line,
+                        column,
+                        message: format!("{} is not in range [0, 100]: {}", path, n)
+                    }
+                ),
+                Some(v) => issues.push(
+                    TeamIssue{
+                        line,
+                        column,
+                        message: format!("{} must be an integer in range [0, 100]: {}", path, v)
+                    }
+                ),
+                None => ()
+            }
+        }
+    }
+
+    // Catch anything else the team deserializer rejects
+    if issues.is_empty() {
+        if let Err(e) = serde_json::from_value::<FootballTeam>(value) {
+            issues.push(
+                TeamIssue{
+                    line: 1,
+                    column: 1,
+                    message: format!("Invalid team: {}", e)
+                }
+            );
+        }
+    }
+    short_name
+}
+
+pub fn validate_teams(args: FbsimTeamValidateArgs) -> Result<(), String> {
+    let mut short_names: HashMap<String, String> = HashMap::new();
+    let mut num_invalid: usize = 0;
+    for file in args.files.iter() {
+        // Load and check the team file
+        let text = match fs::read_to_string(file) {
+            Ok(t) => t,
+            Err(e) => {
+                println!("{}: Error loading team file: {}", file, e);
+                num_invalid += 1;
+                continue;
+            }
+        };
+        let mut issues: Vec<TeamIssue> = Vec::new();
+        let short_name = check_team(&text, TeamFormat::from_path(file), &mut issues);
+
+        // Check the short name against those of the previous files
+        if let Some((short_name, line, column)) = short_name {
+            match short_names.get(&short_name) {
+                Some(other) => issues.push(
+                    TeamIssue{
+                        line,
+                        column,
+                        message: format!("Duplicate short_name {}, also used by {}", short_name, other)
+                    }
+                ),
+                None => {
+                    short_names.insert(short_name, file.clone());
+                }
+            }
+        }
+
+        // Display the problems found in the team file
+        if issues.is_empty() {
+            println!("{}: OK", file);
+        } else {
+            num_invalid += 1;
+            issues.sort_by_key(|issue| (issue.line, issue.column));
+            for issue in issues.iter() {
+                println!("{}:{}:{}: {}", file, issue.line, issue.column, issue.message);
+            }
+        }
+    }
+    if num_invalid > 0 {
+        return Err(format!("{} of {} team files are invalid", num_invalid, args.files.len()));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FORMATS: [TeamFormat; 3] = [TeamFormat::Json, TeamFormat::Toml, TeamFormat::Yaml];
+
+    /// Serialize a team with any changes made to it in the given format
+    fn team_text(format: TeamFormat, change: impl Fn(&mut Map<String, Value>)) -> String {
+        let mut value = serde_json::to_value(FootballTeam::new()).unwrap();
+        change(value.as_object_mut().unwrap());
+        match format {
+            TeamFormat::Json => serde_json::to_string_pretty(&value).unwrap(),
+            TeamFormat::Toml => toml::to_string(&value).unwrap(),
+            TeamFormat::Yaml => serde_norway::to_string(&value).unwrap()
+        }
+    }
+
+    /// The line and column of the key with the given prefix in a team file
+    fn find_key(text: &str, format: TeamFormat, key: &str, table: bool) -> (usize, usize) {
+        let prefix = match (format, table) {
+            (TeamFormat::Json, _) => format!("\"{}\"", key),
+            (TeamFormat::Toml, true) => format!("[{}]", key),
+            (TeamFormat::Toml, false) => format!("{} =", key),
+            (TeamFormat::Yaml, _) => format!("{}:", key)
+        };
+        for (i, line) in text.lines().enumerate() {
+            let trimmed = line.trim_start();
+            if trimmed.starts_with(&prefix) {
+                return (i + 1, line.len() - trimmed.len() + 1);
+            }
+        }
+        panic!("No key {} found in:\n{}", key, text);
+    }
+
+    /// Check a team file, returning its issues as positioned messages
+    fn issues(text: &str, format: TeamFormat) -> Vec<(usize, usize, String)> {
+        let mut issues: Vec<TeamIssue> = Vec::new();
+        check_team(text, format, &mut issues);
+        issues.into_iter().map(|i| (i.line, i.column, i.message)).collect()
+    }
+
+    /// Get a skill level group of a team
+    fn group<'a>(team: &'a mut Map<String, Value>, name: &str) -> &'a mut Map<String, Value> {
+        team.get_mut(name).unwrap().as_object_mut().unwrap()
+    }
+
+    #[test]
+    fn test_valid_team() {
+        for format in FORMATS {
+            let text = team_text(format, |_| ());
+            assert_eq!(issues(&text, format), Vec::new(), "{}", format.name());
+        }
+    }
+
+    #[test]
+    fn test_unknown_key() {
+        for format in FORMATS {
+            let text = team_text(format, |team| {
+                group(team, "offense").insert(String::from("throwing"), Value::from(50));
+            });
+            let (line, column) = find_key(&text, format, "throwing", false);
+            assert_eq!(
+                issues(&text, format),
+                vec![(line, column, String::from("Unknown key: offense.throwing"))],
+                "{}", format.name()
+            );
+        }
+    }
+
+    #[test]
+    fn test_missing_key() {
+        for format in FORMATS {
+            let text = team_text(format, |team| {
+                group(team, "defense").remove("coverage");
+            });
+            let (line, column) = find_key(&text, format, "defense", true);
+            assert_eq!(
+                issues(&text, format),
+                vec![(line, column, String::from("Missing field: defense.coverage"))],
+                "{}", format.name()
+            );
+        }
+    }
+
+    #[test]
+    fn test_out_of_range_key() {
+        for format in FORMATS {
+            let text = team_text(format, |team| {
+                group(team, "coach").insert(String::from("up_tempo"), Value::from(101));
+            });
+            let (line, column) = find_key(&text, format, "up_tempo", false);
+            assert_eq!(
+                issues(&text, format),
+                vec![(line, column, String::from("coach.up_tempo is not in range [0, 100]: 101"))],
+                "{}", format.name()
+            );
+        }
+    }
+}